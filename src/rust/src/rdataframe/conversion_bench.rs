@@ -0,0 +1,73 @@
+//benchmark of R to polars conversion, only built with cargo feature conversion_bench.
+//Gives a reproducible baseline for fast paths in r_to_series.
+
+use crate::rdataframe::r_to_series::robjname2series;
+use extendr_api::prelude::*;
+use std::time::Instant;
+
+//name and R generator function of n elements, for each benchmarked column
+const CASES: [(&str, &str); 7] = [
+    ("double", "function(n) as.double(seq_len(n))"),
+    ("integer_altrep", "function(n) seq_len(n)"),
+    (
+        "string_with_na",
+        "function(n) { x = as.character(seq_len(n)); x[seq(1, n, by = 10)] = NA; x }",
+    ),
+    (
+        "glue_with_na",
+        "function(n) { x = paste0('id_', seq_len(n)); x[seq(1, n, by = 10)] = NA; \
+        structure(x, class = c('glue', 'character')) }",
+    ),
+    (
+        "nested_list",
+        "function(n) lapply(seq_len(max(n %/% 10, 1)), function(i) as.double(1:10))",
+    ),
+    (
+        "factor",
+        "function(n) factor(letters[(seq_len(n) - 1L) %% 26L + 1L], levels = letters)",
+    ),
+    (
+        "date_seq",
+        "function(n) seq(as.Date('1970-01-01'), by = 'day', length.out = n)",
+    ),
+];
+
+fn generate(f: &str, n: i32) -> std::result::Result<Robj, String> {
+    extendr_api::eval_string(f)
+        .map(|f| f.as_function().expect("internal error: not a function"))
+        .and_then(|f| f.call(pairlist!(n)))
+        .map_err(|err| format!("failed to generate benchmark column: {:?}", err))
+}
+
+//time reps conversions of each case of n elements. Returns a named list of equal length
+//columns: case, n, min_ms and median_ms.
+pub fn run(n: i32, reps: i32) -> std::result::Result<List, String> {
+    if n < 1 || reps < 1 {
+        return Err(format!(
+            "n [{}] and reps [{}] must both be at least 1",
+            n, reps
+        ));
+    }
+    let mut min_ms = Vec::with_capacity(CASES.len());
+    let mut median_ms = Vec::with_capacity(CASES.len());
+    for (case, f) in CASES {
+        let x = generate(f, n)?;
+        let mut times: Vec<f64> = (0..reps)
+            .map(|_| {
+                let start = Instant::now();
+                robjname2series(&x, case)
+                    .map(|_| start.elapsed().as_secs_f64() * 1000.0)
+                    .map_err(|err| format!("benchmark case [{}] failed: {}", case, err))
+            })
+            .collect::<std::result::Result<_, String>>()?;
+        times.sort_by(|a, b| a.partial_cmp(b).expect("times are finite"));
+        min_ms.push(times[0]);
+        median_ms.push(times[times.len() / 2]);
+    }
+    Ok(list!(
+        case = CASES.iter().map(|(case, _)| *case).collect::<Vec<_>>(),
+        n = vec![n; CASES.len()],
+        min_ms = min_ms,
+        median_ms = median_ms
+    ))
+}
@@ -1,6 +1,8 @@
 use extendr_api::{extendr, prelude::*, rprintln, Rinternals};
 use polars::prelude::{self as pl, IntoLazy};
 use std::result::Result;
+#[cfg(feature = "conversion_bench")]
+pub mod conversion_bench;
 pub mod r_to_series;
 pub mod read_csv;
 pub mod read_parquet;
@@ -5,6 +5,8 @@ use polars::prelude as pl;
 use extendr_api::prelude::*;
 use polars::prelude::NamedFrom;
 use polars::prelude::IntoSeries;
+use polars::prelude::NewChunkedArray;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 
 // Internal tree structure to contain Series of fully parsed nested Robject.
 // It is easier to resolve concatenated datatype after all elements have been parsed
@@ -14,13 +16,192 @@ use polars::prelude::IntoSeries;
 enum SeriesTree {
     Series(pl::Series),         // an R object likely some vector was converted into a plain Series
     SeriesVec(Vec<SeriesTree>), // an R object was converted into list of Series'
-    SeriesEmptyVec,             // likely an R NULL or list() delayed conversion as corrosponding polars is yet given
+    SeriesEmptyVec,             // likely an R list() delayed conversion as corrosponding polars is yet given
+    SeriesNull,                 // an R NULL, a null element (not an empty one) of any parent list
 }
 
+// Options to deviate from the default conversion. Parsed from a named R list, where any
+// missing name keeps the default.
+#[derive(Debug, Clone, Default)]
+pub struct ConversionOptions {
+    pub int_mode_as_utf8: bool, // import hexmode/octmode as their hex/oct strings, not Int32
+    pub logical_as_int: bool,   // import logicals as Int32 0/1, not Boolean
+    pub time_out_of_range_as_null: bool, // hms times outside [0h, 24h) become null, not an error
+    pub integral_doubles_as_int: bool, // import all-integral doubles as smallest fitting integer type
+    pub raw_as_bytes: bool, // import a raw vector as UInt8 series of its bytes, not one Binary value
+    pub matrix_as_struct: bool, // import a matrix as Struct of its columns, not List per row
+    pub mpfr_as_utf8: bool, // import Rmpfr numbers as Utf8 keeping all digits, not lossy Float64
+    pub int_as_uint32: bool, // import integers as UInt32 of the same bits, e.g. bitmasks, not Int32
+    pub warn_on_dropped_names: bool, // warn when names of a list are dropped, as List has no names
+    pub fractional_date_as_datetime: Option<pl::TimeUnit>, // import Date of fractional days as Datetime
+}
+
+impl ConversionOptions {
+    pub fn from_rlist(list: &List) -> std::result::Result<Self, String> {
+        let mut opts = ConversionOptions::default();
+        for (name, robj) in list.iter() {
+            match name {
+                "int_mode_as_utf8" => opts.int_mode_as_utf8 = robj_to_flag(name, &robj)?,
+                "logical_as_int" => opts.logical_as_int = robj_to_flag(name, &robj)?,
+                "time_out_of_range_as_null" => {
+                    opts.time_out_of_range_as_null = robj_to_flag(name, &robj)?
+                }
+                "integral_doubles_as_int" => {
+                    opts.integral_doubles_as_int = robj_to_flag(name, &robj)?
+                }
+                "raw_as_bytes" => opts.raw_as_bytes = robj_to_flag(name, &robj)?,
+                "matrix_as_struct" => opts.matrix_as_struct = robj_to_flag(name, &robj)?,
+                "mpfr_as_utf8" => opts.mpfr_as_utf8 = robj_to_flag(name, &robj)?,
+                "int_as_uint32" => opts.int_as_uint32 = robj_to_flag(name, &robj)?,
+                "warn_on_dropped_names" => {
+                    opts.warn_on_dropped_names = robj_to_flag(name, &robj)?
+                }
+                "fractional_date_as_datetime" => {
+                    opts.fractional_date_as_datetime = Some(robj_to_time_unit(name, &robj)?)
+                }
+                _ => {
+                    return Err(format!(
+                        "conversion option [{}] is not any of 'int_mode_as_utf8', 'logical_as_int', \
+                        'time_out_of_range_as_null', 'integral_doubles_as_int', 'raw_as_bytes', \
+                        'matrix_as_struct', 'mpfr_as_utf8', 'int_as_uint32', 'warn_on_dropped_names', \
+                        'fractional_date_as_datetime'",
+                        name
+                    ))
+                }
+            }
+        }
+        Ok(opts)
+    }
+}
+
+fn robj_to_flag(name: &str, robj: &Robj) -> std::result::Result<bool, String> {
+    match (robj.as_logical_slice(), robj.len()) {
+        (Some(&[b]), 1) if !b.is_na() => Ok(b.is_true()),
+        _ => Err(format!(
+            "conversion option [{}] must be TRUE or FALSE, not {:?}",
+            name, robj
+        )),
+    }
+}
+
+fn robj_to_time_unit(name: &str, robj: &Robj) -> std::result::Result<pl::TimeUnit, String> {
+    match robj.as_str() {
+        Some(s) if robj.len() == 1 => crate::rdatatype::new_time_unit(s)
+            .map_err(|err| format!("conversion option [{}]: {}", name, err)),
+        _ => Err(format!(
+            "conversion option [{}] must be a TimeUnit 'ns', 'us' or 'ms', not {:?}",
+            name, robj
+        )),
+    }
+}
+
+// Optional runtime counters of converted R objects per Rtype branch, to profile imports.
+// When disabled only one relaxed atomic load is spent per converted R object.
+static CONVERSION_STATS_ON: AtomicBool = AtomicBool::new(false);
+pub const CONVERSION_STATS_BRANCHES: [&str; 7] =
+    ["doubles", "integers", "strings", "logicals", "list", "null", "other"];
+#[allow(clippy::declare_interior_mutable_const)]
+const ZERO: AtomicU64 = AtomicU64::new(0);
+static CONVERSION_CALLS: [AtomicU64; 7] = [ZERO; 7];
+static CONVERSION_ELEMENTS: [AtomicU64; 7] = [ZERO; 7];
+
+pub fn set_conversion_stats(enable: bool) {
+    CONVERSION_STATS_ON.store(enable, Ordering::Relaxed);
+}
+
+pub fn reset_conversion_stats() {
+    for counter in CONVERSION_CALLS.iter().chain(CONVERSION_ELEMENTS.iter()) {
+        counter.store(0, Ordering::Relaxed);
+    }
+}
+
+// counts of (calls, elements) for each of CONVERSION_STATS_BRANCHES
+pub fn get_conversion_stats() -> Vec<(u64, u64)> {
+    CONVERSION_CALLS
+        .iter()
+        .zip(CONVERSION_ELEMENTS.iter())
+        .map(|(calls, elements)| (calls.load(Ordering::Relaxed), elements.load(Ordering::Relaxed)))
+        .collect()
+}
+
+// import R doubles as Float32 instead of Float64, off by default
+static DOUBLES_AS_FLOAT32: AtomicBool = AtomicBool::new(false);
+
+pub fn set_doubles_as_float32(enable: bool) {
+    DOUBLES_AS_FLOAT32.store(enable, Ordering::Relaxed);
+}
+
+pub fn get_doubles_as_float32() -> bool {
+    DOUBLES_AS_FLOAT32.load(Ordering::Relaxed)
+}
+
+fn record_conversion_stats(x: &Robj) {
+    if !CONVERSION_STATS_ON.load(Ordering::Relaxed) {
+        return;
+    }
+    let branch = match x.rtype() {
+        Rtype::Doubles => 0,
+        Rtype::Integers => 1,
+        Rtype::Strings => 2,
+        Rtype::Logicals => 3,
+        Rtype::List => 4,
+        Rtype::Null => 5,
+        _ => 6,
+    };
+    CONVERSION_CALLS[branch].fetch_add(1, Ordering::Relaxed);
+    CONVERSION_ELEMENTS[branch].fetch_add(x.len() as u64, Ordering::Relaxed);
+}
+
+// R classes (or type names for plain vectors) explicitly handled by recursive_robjname2series_tree.
+// Keep in sync with its match arms, when adding a conversion.
+pub const SUPPORTED_R_CLASSES: [&str; 25] = [
+    "numeric",
+    "integer",
+    "character",
+    "glue",
+    "logical",
+    "complex",
+    "integer64",
+    "factor",
+    "Date",
+    "POSIXct",
+    "difftime",
+    "hms",
+    "Duration",
+    "Period",
+    "Surv",
+    "hexmode",
+    "octmode",
+    "numeric_version",
+    "mpfr",
+    "table",
+    "matrix",
+    "rle",
+    "raw",
+    "list",
+    "NULL",
+];
+
 // Main module function: Convert any potentially nested R object handled in three steps
 pub fn robjname2series(x: &Robj, name: &str) -> pl::PolarsResult<pl::Series> {
+    robjname2series_with_options(x, name, &ConversionOptions::default())
+}
+
+pub fn robjname2series_with_options(
+    x: &Robj,
+    name: &str,
+    opts: &ConversionOptions,
+) -> pl::PolarsResult<pl::Series> {
+    // 0 nested factors are cast to Categorical one by one. Use a shared string cache while
+    // converting, such that categorical codes of all list elements match when concatenated.
+    let _string_cache = if x.rtype() == Rtype::List {
+        Some(polars_core::IUseStringCache::new())
+    } else {
+        None
+    };
+
     // 1 parse any (potentially) R structure, into a tree of Series, boubble any parse error
-    let st = recursive_robjname2series_tree(x, name)?;
+    let st = recursive_robjname2series_tree(x, name, opts)?;
 
     // 2 search for first leaf dtype, returns None for empty list or lists of empty lists and so on ...
     let first_leaf_dtype = find_first_leaf_datatype(&st);
@@ -29,11 +210,32 @@ pub fn robjname2series(x: &Robj, name: &str) -> pl::PolarsResult<pl::Series> {
     concat_series_tree(st, &first_leaf_dtype, name)
 }
 
+// Convert into a target DataType. A logical vector of only NA, e.g. a lone R NA, becomes nulls of
+// the target DataType. Anything else is converted as usual and then strictly cast.
+pub fn robjname2series_with_dtype(
+    x: &Robj,
+    name: &str,
+    opts: &ConversionOptions,
+    dtype: &pl::DataType,
+) -> pl::PolarsResult<pl::Series> {
+    let all_na_logical = x.rtype() == Rtype::Logicals
+        && x.as_logical_slice().map_or(false, |l| l.iter().all(|b| b.is_na()));
+    if all_na_logical {
+        let dtype = crate::rdatatype::resolve_unknown(dtype, &pl::DataType::Null);
+        return Ok(pl::Series::full_null(name, x.len(), &dtype));
+    }
+    //any Unknown in dtype e.g. List(Unknown) is inferred from data
+    let s = robjname2series_with_options(x, name, opts)?;
+    let dtype = crate::rdatatype::resolve_unknown(dtype, s.dtype());
+    s.strict_cast(&dtype)
+}
+
 // this function walks the SeriesTree to find the first leaf DataType
 fn find_first_leaf_datatype(st: &SeriesTree) -> Option<pl::DataType> {
     match st {
         SeriesTree::Series(s) => Some(s.dtype().clone()), //an actual leaf type found, return as the answer
         SeriesTree::SeriesEmptyVec => None, //no type to be found here in this empty list return None from here
+        SeriesTree::SeriesNull => None,     //neither in a NULL
         SeriesTree::SeriesVec(sv) => sv //looking deeper in nested structure
             .iter()
             .map(|inner_st| find_first_leaf_datatype(inner_st))
@@ -44,11 +246,110 @@ fn find_first_leaf_datatype(st: &SeriesTree) -> Option<pl::DataType> {
 }
 
 // convert any Robj into a SeriesTree, or a nested SeriesTree if nested Robject
-fn recursive_robjname2series_tree(x: &Robj, name: &str) -> pl::PolarsResult<SeriesTree> {
+fn recursive_robjname2series_tree(
+    x: &Robj,
+    name: &str,
+    opts: &ConversionOptions,
+) -> pl::PolarsResult<SeriesTree> {
+    record_conversion_stats(x);
     let rtype = x.rtype();
 
     // handle any supported Robj
     match rtype {
+        // I() only protects e.g. a list column of a data.frame, convert as without AsIs class
+        _ if x.inherits("AsIs") => {
+            let unwrapped = call_r_fn(
+                "function(x) { class(x) = setdiff(oldClass(x), 'AsIs'); x }",
+                pairlist!(x.clone()),
+            )?;
+            recursive_robjname2series_tree(&unwrapped, name, opts)
+        }
+
+        // a connection is just an integer handle to a file, url, etc., not its content
+        Rtype::Integers if x.inherits("connection") => Err(pl::PolarsError::InvalidOperation(
+            polars::error::ErrString::Owned(format!(
+                "cannot convert connection [{}] to a series, read it into a vector first, \
+                e.g. with readLines() or read.csv()",
+                x.class().and_then(|mut cl| cl.next()).unwrap_or("connection")
+            )),
+        )),
+
+        // nanotime and other timestamps stored as integer64 nanoseconds since epoch
+        Rtype::Doubles
+            if x.inherits("nanotime") || (x.inherits("integer64") && x.inherits("POSIXct")) =>
+        {
+            Ok(SeriesTree::Series(robj_to_nano_datetime_series(x, name)?))
+        }
+
+        Rtype::Doubles if x.inherits("POSIXct") => {
+            Ok(SeriesTree::Series(robj_to_datetime_series(x, name)?))
+        }
+
+        Rtype::Integers | Rtype::Doubles if x.inherits("table") => Ok(SeriesTree::Series(
+            robj_to_table_struct_series(x, name, opts)?,
+        )),
+
+        Rtype::Doubles if x.inherits("integer64") => {
+            Ok(SeriesTree::Series(robj_to_integer64_series(x, name)))
+        }
+
+        // a double Date may hold fractional days, i.e. sub-day precision
+        Rtype::Doubles if x.inherits("Date") && has_fractional_days(x) => {
+            match opts.fractional_date_as_datetime {
+                Some(tu) => Ok(SeriesTree::Series(robj_days_to_datetime_series(x, name, tu))),
+                None => {
+                    warn_truncated_days(name)?;
+                    Ok(SeriesTree::Series(robj_to_date_series(x, name)?))
+                }
+            }
+        }
+
+        Rtype::Doubles | Rtype::Integers if x.inherits("Date") => {
+            Ok(SeriesTree::Series(robj_to_date_series(x, name)?))
+        }
+
+        // hms is a difftime of seconds since midnight
+        Rtype::Doubles | Rtype::Integers if x.inherits("hms") => Ok(SeriesTree::Series(
+            robj_to_time_series(x, name, opts.time_out_of_range_as_null)?,
+        )),
+
+        // lubridate Duration is an S4 double of seconds
+        Rtype::Doubles if x.inherits("Duration") => Ok(SeriesTree::Series(
+            robj_secs_to_duration_series(x, name, pl::TimeUnit::Nanoseconds, 1)?,
+        )),
+
+        // lubridate Period has calendar components of variable length, e.g. months, so it is no
+        // fixed Duration. Its components are kept as a Struct.
+        Rtype::Doubles if x.inherits("Period") => {
+            Ok(SeriesTree::Series(robj_to_period_struct_series(x, name)?))
+        }
+
+        Rtype::Doubles if x.inherits("Surv") => {
+            Ok(SeriesTree::Series(robj_to_surv_struct_series(x, name)?))
+        }
+
+        // any difftime units are converted to the common Nanoseconds, such that difftime
+        // elements of a list with mixed units still share one Duration leaf dtype
+        Rtype::Doubles | Rtype::Integers if x.inherits("difftime") => Ok(SeriesTree::Series(
+            robj_to_duration_series(x, name, pl::TimeUnit::Nanoseconds)?,
+        )),
+
+        Rtype::Doubles if opts.integral_doubles_as_int && integral_doubles_range(x).is_some() => {
+            Ok(SeriesTree::Series(robj_to_narrowed_int_series(x, name)?))
+        }
+
+        // lossy, f64 values are rounded to nearest f32 and beyond f32 range become +-inf
+        Rtype::Doubles if get_doubles_as_float32() => {
+            let rdouble: Doubles = x.try_into().expect("as matched");
+            let ca = pl::Float32Chunked::from_iter_options(
+                name,
+                rdouble
+                    .iter()
+                    .map(|x| if x.is_na() { None } else { Some(x.0 as f32) }),
+            );
+            Ok(SeriesTree::Series(ca.into_series()))
+        }
+
         Rtype::Doubles => {
             let rdouble: Doubles = x.try_into().expect("as matched");
             if rdouble.no_na().is_true() {
@@ -57,17 +358,38 @@ fn recursive_robjname2series_tree(x: &Robj, name: &str) -> pl::PolarsResult<Seri
                     x.as_real_slice().unwrap(),
                 )))
             } else {
-                let mut s: pl::Series = rdouble//convert R NAs to rust options
-                    .iter()
-                    .map(|x| if x.is_na() { None } else { Some(x.0) })
-                    .collect();
-                s.rename(name);
-                Ok(SeriesTree::Series(s))
+                //convert R NAs to rust options, name is set directly to skip a rename
+                let ca = pl::Float64Chunked::from_iter_options(
+                    name,
+                    rdouble.iter().map(|x| if x.is_na() { None } else { Some(x.0) }),
+                );
+                Ok(SeriesTree::Series(ca.into_series()))
             }
         }
 
+        Rtype::Strings if robj_matrix_dim(x).is_some() => Ok(SeriesTree::Series(
+            robj_to_utf8_matrix_series(x, name, opts)?,
+        )),
+
         Rtype::Strings => Ok(SeriesTree::Series(robj_to_utf8_series(x.try_into().expect("as matched"), name))),
 
+        Rtype::Complexes => Ok(SeriesTree::Series(robj_to_complex_struct_series(x, name)?)),
+
+        Rtype::Logicals if opts.logical_as_int => {
+            let logicals: Logicals = x.try_into().unwrap();
+            let ca = pl::Int32Chunked::from_iter_options(
+                name,
+                logicals.iter().map(|x| {
+                    if x.is_na() {
+                        None
+                    } else {
+                        Some(x.is_true() as i32)
+                    }
+                }),
+            );
+            Ok(SeriesTree::Series(ca.into_series()))
+        }
+
         Rtype::Logicals => {
             let logicals: Logicals = x.try_into().unwrap();
             let s: Vec<Option<bool>> = logicals
@@ -77,36 +399,107 @@ fn recursive_robjname2series_tree(x: &Robj, name: &str) -> pl::PolarsResult<Seri
             Ok(SeriesTree::Series(pl::Series::new(name, s)))
         }
 
+        // an explicit NA level, as of addNA(), is NA_character_ as character, and becomes null
+        // like any NA value. Categorical has no null category.
         Rtype::Integers if x.inherits("factor") => Ok(SeriesTree::Series(
             robj_to_utf8_series(x.as_character_factor().try_into().expect("as_character_factor() enforces same type"), name)
                 .cast(&pl::DataType::Categorical(None))
                 .expect("as matched"),
         )),
 
+        // hexmode/octmode are plain integers with a display class, by default imported as Int32
+        Rtype::Integers
+            if opts.int_mode_as_utf8 && (x.inherits("hexmode") || x.inherits("octmode")) =>
+        {
+            //format() dispatches to format.hexmode / format.octmode
+            let formatted = call_r_fn(
+                "function(x) { out = format(x); out[is.na(x)] = NA_character_; out }",
+                pairlist!(x.clone()),
+            )?;
+            Ok(SeriesTree::Series(robj_to_utf8_series(
+                formatted.try_into().expect("format returns character"),
+                name,
+            )))
+        }
+
+        // reinterpret the two's complement bits, e.g. -1L is 2^32 - 1. NA_integer_ has the bits of
+        // 2^31, but is imported as null
+        Rtype::Integers if opts.int_as_uint32 => {
+            let rints = x.as_integers().expect("as matched");
+            Ok(SeriesTree::Series(
+                pl::UInt32Chunked::from_iter_options(
+                    name,
+                    rints.iter().map(|x| if x.is_na() { None } else { Some(x.0 as u32) }),
+                )
+                .into_series(),
+            ))
+        }
+
         Rtype::Integers => {
             let rints = x.as_integers().expect("as matched");
             let s = if rints.no_na().is_true() {
                 pl::Series::new(name, x.as_integer_slice().expect("as matched"))
             } else {
-                //convert R NAs to rust options
-                let mut s: pl::Series = rints
-                    .iter()
-                    .map(|x| if x.is_na() { None } else { Some(x.0) })
-                    .collect();
-                s.rename(name);
-                s
+                //convert R NAs to rust options, name is set directly to skip a rename
+                pl::Int32Chunked::from_iter_options(
+                    name,
+                    rints.iter().map(|x| if x.is_na() { None } else { Some(x.0) }),
+                )
+                .into_series()
             };
             Ok(SeriesTree::Series(s))
         },
 
-        Rtype::Null => Ok(SeriesTree::SeriesEmptyVec), // flag NULL with this enum, to resolve polars type later
+        Rtype::Null => Ok(SeriesTree::SeriesNull), // flag NULL with this enum, to resolve polars type later
+
+        // a raw matrix holds one fixed-width byte record per row
+        Rtype::Raw if robj_matrix_dim(x).is_some() => {
+            Ok(SeriesTree::Series(robj_to_raw_matrix_series(x, name)))
+        }
+
+        Rtype::Raw if opts.raw_as_bytes => Ok(SeriesTree::Series(pl::Series::new(
+            name,
+            x.as_raw_slice().expect("as matched"),
+        ))),
+
+        // a raw vector is one opaque blob, e.g. from serialize(), import as a single Binary value
+        Rtype::Raw => {
+            let bytes = x.as_raw_slice().expect("as matched");
+            let ca = pl::BinaryChunked::from_slice(name, &[bytes]);
+            Ok(SeriesTree::Series(ca.into_series()))
+        }
+
+        // run-length encodings are decoded, the Series has one element per R element. base R rle
+        // is decoded by inverse.rle(), S4Vectors Rle by its as.vector() S4 method.
+        Rtype::List | Rtype::S4 if x.inherits("rle") || x.inherits("Rle") => {
+            let decode = if x.inherits("rle") { "inverse.rle" } else { "as.vector" };
+            let decoded = call_r_fn(decode, pairlist!(x.clone()))?;
+            recursive_robjname2series_tree(&decoded, name, opts)
+        }
+
+        // Rmpfr numbers, as Float64 or with mpfr_as_utf8 as strings of all digits
+        Rtype::List | Rtype::S4 if x.inherits("mpfr") => {
+            Ok(SeriesTree::Series(robj_to_mpfr_series(x, name, opts)?))
+        }
+
+        // versions are lists of integer vectors, import their canonical strings e.g. "1.2.3"
+        Rtype::List if x.inherits("numeric_version") => {
+            let strs = call_r_fn("as.character", pairlist!(x.clone()))?;
+            Ok(SeriesTree::Series(robj_to_utf8_series(
+                strs.try_into().expect("as.character returns character"),
+                name,
+            )))
+        }
 
         Rtype::List => { // Recusively handle elements of list
+            if opts.warn_on_dropped_names {
+                warn_dropped_list_names(x, name)?;
+            }
             let result_series_vec: pl::PolarsResult<Vec<SeriesTree>> = x
                 .as_list()
                 .unwrap()
                 .iter()
-                .map(|(name, robj)| recursive_robjname2series_tree(&robj, name))
+                .map(|(name, robj)| recursive_robjname2series_tree(&robj, name, opts))
                 .collect();
             result_series_vec.map(|vst| {
                 if vst.len() == 0 {
@@ -117,12 +510,72 @@ fn recursive_robjname2series_tree(x: &Robj, name: &str) -> pl::PolarsResult<Seri
             })
         }
 
+        Rtype::Language | Rtype::Expressions | Rtype::Symbol => {
+            Err(pl::PolarsError::InvalidOperation(polars::error::ErrString::Owned(format!(
+                "R expression/call objects cannot be converted to a Series, got rtype {:?}. \
+                Evaluate it first, or deparse() it to a string.",
+                rtype
+            ))))
+        }
+
+        // e.g. external pointer backed frames of other packages, if an extractor is registered
+        Rtype::ExternalPtr | Rtype::Environment | Rtype::S4 if has_registered_extractor(x) => {
+            robj_to_struct_series_via_extractor(x, name, opts)
+        }
+
         _ => Err(pl::PolarsError::NotFound(polars::error::ErrString::Owned(
             format!("new series from rtype {:?} is not supported (yet)", rtype),
         ))),
     }
 }
 
+// true if an extractor is registered via pl$register_extractor() for any class of x. Other
+// classed pointers, e.g. rpolars' own objects or R6 objects, are not supported.
+fn has_registered_extractor(x: &Robj) -> bool {
+    x.class().is_some()
+        && call_r_fn("rpolars:::has_registered_extractor", pairlist!(x.clone()))
+            .map(|found| found.as_bool() == Some(true))
+            .unwrap_or(false)
+}
+
+// run the extractor registered in R via pl$register_extractor() for the class of x. The
+// extractor returns a named list of R vectors and/or Series, which become the Struct fields.
+// The pointer of x is never dereferenced here, validating it is the duty of the extractor.
+fn robj_to_struct_series_via_extractor(
+    x: &Robj,
+    name: &str,
+    opts: &ConversionOptions,
+) -> pl::PolarsResult<SeriesTree> {
+    let err = |msg: String| pl::PolarsError::ComputeError(polars::error::ErrString::Owned(msg));
+    let extracted = call_r_fn("rpolars:::run_registered_extractor", pairlist!(x.clone()))?;
+
+    let list = extracted
+        .as_list()
+        .ok_or_else(|| err("registered extractor must return a named list".into()))?;
+    let fields: pl::PolarsResult<Vec<pl::Series>> = list
+        .iter()
+        .enumerate()
+        .map(|(i, (field_name, robj))| {
+            if field_name.is_empty() || field_name == "NA" {
+                return Err(err(format!(
+                    "registered extractor returned an unnamed element at position {}",
+                    i + 1
+                )));
+            }
+            let mut s = if robj.inherits("Series") {
+                super::Series::inner_from_robj_clone(&robj).map_err(|e| err(e.into()))?.0
+            } else {
+                robjname2series_with_options(&robj, field_name, opts)?
+            };
+            s.rename(field_name);
+            Ok(s)
+        })
+        .collect();
+
+    let ca = pl::StructChunked::new(name, &fields?)?;
+    Ok(SeriesTree::Series(ca.into_series()))
+}
+
 // consume nested SeriesTree and return concatenated Series or an appropriate Error
 fn concat_series_tree(
     st: SeriesTree,
@@ -131,7 +584,9 @@ fn concat_series_tree(
 ) -> pl::PolarsResult<pl::Series> {
     match st {
         SeriesTree::Series(s) => Ok(s), // SeriesTree is just a regular Series, return as is
-        SeriesTree::SeriesEmptyVec => { // Create Series of empty array and cast to the found leaf_dtype.
+        // Create Series of empty array and cast to the found leaf_dtype. A NULL is only a null
+        // element within a list, on its own it is empty as list().
+        SeriesTree::SeriesEmptyVec | SeriesTree::SeriesNull => {
             let empty_list_series = pl::Series::new(name, [0f64; 0]).to_list()?.slice(0, 0);
             let s = empty_list_series.into_series();
             if let Some(leaf_dt_ref) = leaf_dtype {
@@ -145,14 +600,30 @@ fn concat_series_tree(
         ),
         SeriesTree::SeriesVec(sv) => {
             
-            // concat any deeper nested parts of SeriesTree
-            let series_vec_result: pl::PolarsResult<Vec<pl::Series>> = sv
+            // concat any deeper nested parts of SeriesTree. Inner names are discarded when
+            // the series are wrapped as list elements, pass "" to not allocate any names.
+            // NULL elements are None, to become null elements.
+            let series_vec_result: pl::PolarsResult<Vec<Option<pl::Series>>> = sv
                 .into_iter()
-                .map(|inner_st| concat_series_tree(inner_st, leaf_dtype, ""))
+                .map(|inner_st| match inner_st {
+                    SeriesTree::SeriesNull => Ok(None),
+                    inner_st => concat_series_tree(inner_st, leaf_dtype, "").map(Some),
+                })
                 .collect();
 
             // boubble any errors
-            let series_vec = series_vec_result?;
+            let opt_series_vec = series_vec_result?;
+            let has_null = opt_series_vec.iter().any(|opt_s| opt_s.is_none());
+            let series_vec: Vec<pl::Series> = opt_series_vec.iter().flatten().cloned().collect();
+
+            // only NULL elements, a list of nulls of leaf_dtype, Float64 if none found
+            if series_vec.is_empty() {
+                let inner_dtype = leaf_dtype.clone().unwrap_or(pl::DataType::Float64);
+                return Ok(
+                    pl::ListChunked::full_null_with_dtype(name, opt_series_vec.len(), &inner_dtype)
+                        .into_series(),
+                );
+            }
 
             // check for any type mismatch to avoid polars panics
             let mut s_iter = series_vec.iter();
@@ -166,23 +637,579 @@ fn concat_series_tree(
                 }
             }
 
-            // use polars new method to concat concatenated series
+            // with NULL elements collect a ListChunked, which marks None as null
+            if has_null {
+                let mut s = opt_series_vec
+                    .into_iter()
+                    .collect::<pl::ListChunked>()
+                    .into_series();
+                s.rename(name);
+                return Ok(s);
+            }
+
+            // use polars new method to concat concatenated series, this also sets the only name
+            // that survives. No further rename needed.
             Ok(pl::Series::new(name, series_vec))
         }
     }
 }
 
+//raise an R warning listing any non-empty names of list x, which a List Series cannot keep
+fn warn_dropped_list_names(x: &Robj, name: &str) -> pl::PolarsResult<()> {
+    let names: Vec<&str> = x
+        .names()
+        .map(|names| names.filter(|n| !n.is_empty() && !n.is_na()).collect())
+        .unwrap_or_default();
+    if names.is_empty() {
+        return Ok(());
+    }
+    let msg = format!(
+        "names of list [{}] are dropped in conversion: {}",
+        name,
+        names.join(", ")
+    );
+    r_warning(&msg)
+}
+
+//evaluate R code to a function, e.g. a function name or definition, and call it with args
+fn call_r_fn(code: &str, args: Pairlist) -> pl::PolarsResult<Robj> {
+    extendr_api::eval_string(code)
+        .and_then(|f| {
+            f.as_function()
+                .ok_or_else(|| extendr_api::Error::Other(format!("[{}] is not a function", code)))
+        })
+        .and_then(|f| f.call(args))
+        .map_err(|err| {
+            pl::PolarsError::ComputeError(polars::error::ErrString::Owned(format!(
+                "R function [{}] failed: {:?}",
+                code, err
+            )))
+        })
+}
+
+//raise an R warning without call, the conversion continues
+fn r_warning(msg: &str) -> pl::PolarsResult<()> {
+    call_r_fn("function(msg) warning(msg, call. = FALSE)", pairlist!(msg)).map(|_| ())
+}
+
 //handle R character/strings to utf8
 fn robj_to_utf8_series(rstrings: Strings, name: &str) -> pl::Series {
+    //any character subclass, e.g. glue, is plain character. Collect straight into the
+    //ChunkedArray, no intermediate Vec of all strings.
     if rstrings.no_na().is_true() {
-        pl::Series::new(name, rstrings.as_robj().as_str_vector().unwrap())
+        pl::Utf8Chunked::from_iter_values(name, rstrings.iter().map(|x| x.as_str())).into_series()
     } else {
         //convert R NAs to rust options
-        let s: Vec<Option<&str>> = rstrings
+        pl::Utf8Chunked::from_iter_options(
+            name,
+            rstrings
+                .iter()
+                .map(|x| if x.is_na() { None } else { Some(x.as_str()) }),
+        )
+        .into_series()
+    }
+}
+
+//number of rows and columns, if x has a 2D dim attribute
+fn robj_matrix_dim(x: &Robj) -> Option<(usize, usize)> {
+    match x.get_attrib("dim")?.as_integer_slice()? {
+        &[nrow, ncol] if !nrow.is_na() && !ncol.is_na() => Some((nrow as usize, ncol as usize)),
+        _ => None,
+    }
+}
+
+//column names of a matrix, as its colnames or V1, V2, ... if unnamed
+fn robj_matrix_colnames(x: &Robj, ncol: usize) -> Vec<String> {
+    x.get_attrib("dimnames")
+        .and_then(|dn| dn.as_list())
+        .and_then(|dn| dn.iter().nth(1))
+        .and_then(|(_, cn)| cn.as_string_vector())
+        .filter(|cn| cn.len() == ncol)
+        .unwrap_or_else(|| (1..=ncol).map(|j| format!("V{}", j)).collect())
+}
+
+//handle R raw matrix (column-major) to List(UInt8) with the bytes of one row per element
+fn robj_to_raw_matrix_series(x: &Robj, name: &str) -> pl::Series {
+    let (nrow, ncol) = robj_matrix_dim(x).expect("as matched");
+    let bytes = x.as_raw_slice().expect("as matched");
+    let lc: pl::ListChunked = (0..nrow)
+        .map(|i| {
+            let row: Vec<u8> = (0..ncol).map(|j| bytes[j * nrow + i]).collect();
+            Some(pl::Series::new("", row))
+        })
+        .collect();
+    let mut s = lc.into_series();
+    s.rename(name);
+    s
+}
+
+//handle R character matrix (column-major) to List(Utf8) with one element per row, or with
+//matrix_as_struct to a Struct of one Utf8 field per column.
+fn robj_to_utf8_matrix_series(
+    x: &Robj,
+    name: &str,
+    opts: &ConversionOptions,
+) -> pl::PolarsResult<pl::Series> {
+    let (nrow, ncol) = robj_matrix_dim(x).expect("as matched");
+    let rstrings: Strings = x.clone().try_into().expect("as matched");
+    let vals: Vec<Option<&str>> = rstrings
+        .iter()
+        .map(|x| if x.is_na() { None } else { Some(x.as_str()) })
+        .collect();
+    if opts.matrix_as_struct {
+        let fields: Vec<pl::Series> = robj_matrix_colnames(x, ncol)
             .iter()
-            .map(|x| if x.is_na() { None } else { Some(x.as_str()) })
+            .enumerate()
+            .map(|(j, col_name)| pl::Series::new(col_name, &vals[j * nrow..(j + 1) * nrow]))
+            .collect();
+        Ok(pl::StructChunked::new(name, &fields)?.into_series())
+    } else {
+        let lc: pl::ListChunked = (0..nrow)
+            .map(|i| {
+                let row: Vec<Option<&str>> = (0..ncol).map(|j| vals[j * nrow + i]).collect();
+                Some(pl::Series::new("", row))
+            })
             .collect();
-        let s = pl::Series::new(name, s);
-        s
+        let mut s = lc.into_series();
+        s.rename(name);
+        Ok(s)
+    }
+}
+
+//handle R POSIXct (seconds since epoch as doubles) to Datetime in microseconds
+//a missing or empty tzone attribute means local/naive time in R and becomes Datetime without
+//a time zone. It is not defaulted to UTC as that would change the wall-clock values.
+fn robj_to_datetime_series(x: &Robj, name: &str) -> pl::PolarsResult<pl::Series> {
+    let tz = robj_to_tz(x)?;
+
+    let rdouble: Doubles = x.try_into().expect("as matched");
+    let ca = pl::Int64Chunked::from_iter_options(
+        name,
+        rdouble.iter().map(|x| {
+            if x.is_na() || !x.0.is_finite() {
+                None
+            } else {
+                Some((x.0 * 1_000_000.0).round() as i64)
+            }
+        }),
+    );
+
+    Ok(ca
+        .into_datetime(pl::TimeUnit::Microseconds, tz)
+        .into_series())
+}
+
+//handle integer64 backed timestamps to Datetime(Nanoseconds, tz), where tz is the tzone
+//attribute if set. nanotime has no tzone, its instants are UTC.
+fn robj_to_nano_datetime_series(x: &Robj, name: &str) -> pl::PolarsResult<pl::Series> {
+    let tz = robj_to_tz(x)?.or_else(|| Some("UTC".to_string()));
+    let s = robj_to_integer64_series(x, name);
+    Ok(s.i64()?
+        .clone()
+        .into_datetime(pl::TimeUnit::Nanoseconds, tz)
+        .into_series())
+}
+
+//handle R difftime to Duration of chosen TimeUnit
+//whole numbers are converted with exact integer arithmetic, e.g. days are 86400s exactly.
+//Any fractional part of a value is scaled and rounded to nearest whole TimeUnit.
+fn robj_to_duration_series(
+    x: &Robj,
+    name: &str,
+    tu: pl::TimeUnit,
+) -> pl::PolarsResult<pl::Series> {
+    let unit_secs = difftime_unit_secs(x)?;
+    robj_secs_to_duration_series(x, name, tu, unit_secs)
+}
+
+fn difftime_unit_secs(x: &Robj) -> pl::PolarsResult<i64> {
+    match x.get_attrib("units").as_ref().and_then(|u| u.as_str()) {
+        Some("secs") => Ok(1),
+        Some("mins") => Ok(60),
+        Some("hours") => Ok(3_600),
+        Some("days") => Ok(86_400),
+        Some("weeks") => Ok(604_800),
+        other => Err(pl::PolarsError::ComputeError(
+            polars::error::ErrString::Owned(format!(
+                "difftime units attribute {:?} is not any of 'secs', 'mins', 'hours', 'days' or 'weeks'",
+                other
+            )),
+        )),
+    }
+}
+
+//handle doubles or integers of a unit of unit_secs seconds to Duration of chosen TimeUnit
+fn robj_secs_to_duration_series(
+    x: &Robj,
+    name: &str,
+    tu: pl::TimeUnit,
+    unit_secs: i64,
+) -> pl::PolarsResult<pl::Series> {
+    let per_sec: i64 = match tu {
+        pl::TimeUnit::Nanoseconds => 1_000_000_000,
+        pl::TimeUnit::Microseconds => 1_000_000,
+        pl::TimeUnit::Milliseconds => 1_000,
+    };
+    let factor = unit_secs * per_sec;
+
+    let values: Vec<Option<f64>> = match x.rtype() {
+        Rtype::Integers => x
+            .as_integers()
+            .expect("as matched")
+            .iter()
+            .map(|x| if x.is_na() { None } else { Some(x.0 as f64) })
+            .collect(),
+        _ => {
+            let rdouble: Doubles = x.try_into().expect("as matched");
+            rdouble
+                .iter()
+                .map(|x| if x.is_na() { None } else { Some(x.0) })
+                .collect()
+        }
+    };
+
+    let ticks: pl::PolarsResult<Vec<Option<i64>>> = values
+        .into_iter()
+        .map(|opt| match opt {
+            None => Ok(None),
+            Some(val) if !val.is_finite() => Ok(None),
+            Some(val) => {
+                let whole = val.trunc();
+                let frac_ticks = (val.fract() * factor as f64).round() as i64;
+                (whole as i64)
+                    .checked_mul(factor)
+                    .and_then(|ticks| ticks.checked_add(frac_ticks))
+                    .filter(|_| whole.abs() < i64::MAX as f64)
+                    .map(Some)
+                    .ok_or_else(|| {
+                        pl::PolarsError::ComputeError(polars::error::ErrString::Owned(format!(
+                            "difftime value {} overflows a Duration in {:?}",
+                            val, tu
+                        )))
+                    })
+            }
+        })
+        .collect();
+
+    Ok(pl::Int64Chunked::from_iter_options(name, ticks?.into_iter())
+        .into_duration(tu)
+        .into_series())
+}
+
+//min and max of doubles if all non-NA values are integral and within i64, and any is non-NA
+fn integral_doubles_range(x: &Robj) -> Option<(f64, f64)> {
+    let rdouble: Doubles = x.try_into().ok()?;
+    let mut range: Option<(f64, f64)> = None;
+    for val in rdouble.iter().filter(|x| !x.is_na()).map(|x| x.0) {
+        // 2^63 is exact as f64, i64::MAX is not
+        if val.fract() != 0.0 || !(-9_223_372_036_854_775_808.0..9_223_372_036_854_775_808.0).contains(&val) {
+            return None;
+        }
+        range = Some(match range {
+            None => (val, val),
+            Some((min, max)) => (min.min(val), max.max(val)),
+        });
+    }
+    range
+}
+
+//handle all-integral R doubles to the smallest integer type fitting the observed min and max,
+//such that e.g. 3e9 becomes Int64 and does not overflow an Int32
+fn robj_to_narrowed_int_series(x: &Robj, name: &str) -> pl::PolarsResult<pl::Series> {
+    let (min, max) = integral_doubles_range(x).expect("as matched");
+    let fits = |lo: f64, hi: f64| min >= lo && max <= hi;
+    let dtype = if fits(i8::MIN as f64, i8::MAX as f64) {
+        pl::DataType::Int8
+    } else if fits(i16::MIN as f64, i16::MAX as f64) {
+        pl::DataType::Int16
+    } else if fits(i32::MIN as f64, i32::MAX as f64) {
+        pl::DataType::Int32
+    } else {
+        pl::DataType::Int64
+    };
+    let rdouble: Doubles = x.try_into().expect("as matched");
+    pl::Int64Chunked::from_iter_options(
+        name,
+        rdouble
+            .iter()
+            .map(|x| if x.is_na() { None } else { Some(x.0 as i64) }),
+    )
+    .into_series()
+    .cast(&dtype)
+}
+
+//handle bit64 integer64 to Int64. bit64 stores the i64 bits in the doubles, and uses the bit
+//pattern of i64::MIN (0x8000000000000000) as NA. So i64::MIN is always null, it can not be a
+//genuine value in bit64, which limits its range to [-2^63+1, 2^63-1].
+fn robj_to_integer64_series(x: &Robj, name: &str) -> pl::Series {
+    const NA_INTEGER64: i64 = i64::MIN;
+    let vals = x.as_real_slice().expect("as matched");
+    pl::Int64Chunked::from_iter_options(
+        name,
+        vals.iter().map(|val| match val.to_bits() as i64 {
+            NA_INTEGER64 => None,
+            i => Some(i),
+        }),
+    )
+    .into_series()
+}
+
+//handle R contingency table to a long-form Struct, one element per cell. There is one Utf8 field
+//per dimension, named as the dimnames (as.data.frame.table gives Var1, Var2, ... if unnamed),
+//and a last field "count" of the cell counts.
+fn robj_to_table_struct_series(
+    x: &Robj,
+    name: &str,
+    opts: &ConversionOptions,
+) -> pl::PolarsResult<pl::Series> {
+    let long = call_r_fn(
+        "function(x) as.data.frame(x, responseName = 'count', stringsAsFactors = FALSE)",
+        pairlist!(x.clone()),
+    )?;
+    let fields: pl::PolarsResult<Vec<pl::Series>> = long
+        .as_list()
+        .expect("as.data.frame returns a list")
+        .iter()
+        .map(|(field_name, robj)| robjname2series_with_options(&robj, field_name, opts))
+        .collect();
+    Ok(pl::StructChunked::new(name, &fields?)?.into_series())
+}
+
+//handle lubridate Period to Struct{year, month, day, hour, minute, second}, all Float64. Seconds
+//are the S4 .Data, the other components are slots, which R stores as attributes.
+fn robj_to_period_struct_series(x: &Robj, name: &str) -> pl::PolarsResult<pl::Series> {
+    let to_f64_series = |field: &str, robj: &Robj| -> pl::PolarsResult<pl::Series> {
+        let vals = robj.as_real_slice().ok_or_else(|| {
+            pl::PolarsError::ComputeError(polars::error::ErrString::Owned(format!(
+                "lubridate Period component [{}] is not double",
+                field
+            )))
+        })?;
+        Ok(pl::Float64Chunked::from_iter_options(
+            field,
+            vals.iter().map(|val| if val.is_na() { None } else { Some(*val) }),
+        )
+        .into_series())
+    };
+    let mut fields = Vec::with_capacity(6);
+    for slot in ["year", "month", "day", "hour", "minute"] {
+        let robj = x.get_attrib(slot).ok_or_else(|| {
+            pl::PolarsError::ComputeError(polars::error::ErrString::Owned(format!(
+                "lubridate Period has no [{}] slot",
+                slot
+            )))
+        })?;
+        fields.push(to_f64_series(slot, &robj)?);
+    }
+    fields.push(to_f64_series("second", x)?);
+    Ok(pl::StructChunked::new(name, &fields)?.into_series())
+}
+
+//handle survival Surv, a matrix of one row per subject, to Struct{time, [time2,] status}.
+//Times are Float64 and status is Int32. Surv types map as:
+// - right, left, mstate: (time, status) to time, status
+// - counting: (start, stop, status) to time, time2, status
+// - interval, interval2: (time1, time2, status) to time, time2, status
+fn robj_to_surv_struct_series(x: &Robj, name: &str) -> pl::PolarsResult<pl::Series> {
+    let (nrow, ncol) = robj_matrix_dim(x).ok_or_else(|| {
+        pl::PolarsError::ComputeError(polars::error::ErrString::Owned(
+            "Surv object has no matrix dim attribute".into(),
+        ))
+    })?;
+    let field_names: &[&str] = match ncol {
+        2 => &["time", "status"],
+        3 => &["time", "time2", "status"],
+        _ => {
+            return Err(pl::PolarsError::ComputeError(polars::error::ErrString::Owned(
+                format!("Surv object must have 2 or 3 columns, not {}", ncol),
+            )))
+        }
+    };
+    let vals = x.as_real_slice().expect("as matched");
+    let fields: Vec<pl::Series> = field_names
+        .iter()
+        .enumerate()
+        .map(|(j, field_name)| {
+            let col = vals[j * nrow..(j + 1) * nrow]
+                .iter()
+                .map(|val| if val.is_na() { None } else { Some(*val) });
+            if *field_name == "status" {
+                pl::Int32Chunked::from_iter_options(field_name, col.map(|v| v.map(|v| v as i32)))
+                    .into_series()
+            } else {
+                pl::Float64Chunked::from_iter_options(field_name, col).into_series()
+            }
+        })
+        .collect();
+    Ok(pl::StructChunked::new(name, &fields)?.into_series())
+}
+
+//handle R complex to Struct{re: Float64, im: Float64}. A value with any NA component is NA as a
+//whole, then both fields are null. NaN components are not NA and are kept as NaN.
+fn robj_to_complex_struct_series(x: &Robj, name: &str) -> pl::PolarsResult<pl::Series> {
+    let component = |f: &str| -> pl::PolarsResult<Vec<f64>> {
+        let vals = call_r_fn(f, pairlist!(x.clone()))?;
+        Ok(vals.as_real_vector().expect("Re/Im return doubles"))
+    };
+    let (re, im) = (component("Re")?, component("Im")?);
+    let is_na: Vec<bool> = re.iter().zip(im.iter()).map(|(r, i)| r.is_na() || i.is_na()).collect();
+    let field = |field_name: &str, vals: Vec<f64>| {
+        pl::Float64Chunked::from_iter_options(
+            field_name,
+            vals.into_iter()
+                .zip(is_na.iter())
+                .map(|(val, na)| if *na { None } else { Some(val) }),
+        )
+        .into_series()
+    };
+    let fields = [field("re", re), field("im", im)];
+    Ok(pl::StructChunked::new(name, &fields)?.into_series())
+}
+
+//handle Rmpfr arbitrary precision numbers to Float64, rounded to nearest double, or with
+//mpfr_as_utf8 to Utf8 of all their digits. Rmpfr S4 methods do the work, so the package must be
+//loaded, which it is when such objects exist.
+fn robj_to_mpfr_series(
+    x: &Robj,
+    name: &str,
+    opts: &ConversionOptions,
+) -> pl::PolarsResult<pl::Series> {
+    let f = if opts.mpfr_as_utf8 {
+        "function(x) {s = format(x); s[is.na(x)] = NA_character_; s}"
+    } else {
+        "function(x) as.numeric(x)"
+    };
+    let converted = call_r_fn(f, pairlist!(x.clone()))?;
+    if opts.mpfr_as_utf8 {
+        Ok(robj_to_utf8_series(converted.try_into().expect("format returns character"), name))
+    } else {
+        Ok(pl::Float64Chunked::from_iter_options(
+            name,
+            converted
+                .as_real_slice()
+                .expect("as.numeric returns double")
+                .iter()
+                .map(|val| if val.is_na() { None } else { Some(*val) }),
+        )
+        .into_series())
     }
 }
+
+//handle R hms to Time, which physically is i64 nanoseconds since midnight in [0, 24h).
+fn robj_to_time_series(
+    x: &Robj,
+    name: &str,
+    out_of_range_as_null: bool,
+) -> pl::PolarsResult<pl::Series> {
+    const NS_PER_DAY: i64 = 86_400_000_000_000;
+    let ns = robj_to_duration_series(x, name, pl::TimeUnit::Nanoseconds)?.cast(&pl::DataType::Int64)?;
+    let ticks: pl::PolarsResult<Vec<Option<i64>>> = ns
+        .i64()?
+        .into_iter()
+        .map(|opt| match opt {
+            Some(t) if !(0..NS_PER_DAY).contains(&t) => {
+                if out_of_range_as_null {
+                    Ok(None)
+                } else {
+                    Err(pl::PolarsError::ComputeError(polars::error::ErrString::Owned(format!(
+                        "hms value of {} seconds is not a time of day within [0, 86400) seconds",
+                        t as f64 / 1e9
+                    ))))
+                }
+            }
+            opt => Ok(opt),
+        })
+        .collect();
+
+    Ok(pl::Int64Chunked::from_iter_options(name, ticks?.into_iter())
+        .into_time()
+        .into_series())
+}
+
+fn has_fractional_days(x: &Robj) -> bool {
+    x.as_real_slice()
+        .expect("as matched")
+        .iter()
+        .any(|days| days.is_finite() && days.fract() != 0.0)
+}
+
+fn warn_truncated_days(name: &str) -> pl::PolarsResult<()> {
+    let msg = format!(
+        "Date [{}] has fractional days, which are truncated. Use conversion option \
+        fractional_date_as_datetime to import it as Datetime instead",
+        name
+    );
+    r_warning(&msg)
+}
+
+//scale double days since epoch to a Datetime without time zone of the chosen TimeUnit
+fn robj_days_to_datetime_series(x: &Robj, name: &str, tu: pl::TimeUnit) -> pl::Series {
+    let per_day = match tu {
+        pl::TimeUnit::Nanoseconds => 86_400_000_000_000.0,
+        pl::TimeUnit::Microseconds => 86_400_000_000.0,
+        pl::TimeUnit::Milliseconds => 86_400_000.0,
+    };
+    let rdouble: Doubles = x.try_into().expect("as matched");
+    pl::Int64Chunked::from_iter_options(
+        name,
+        rdouble.iter().map(|x| {
+            if x.is_na() || !x.0.is_finite() {
+                None
+            } else {
+                Some((x.0 * per_day).round() as i64)
+            }
+        }),
+    )
+    .into_datetime(tu, None)
+    .into_series()
+}
+
+//handle R Date (days since epoch as doubles or integers) to Date, which physically is i32 days.
+//Fractional days are floored. NA, non-finite and days beyond the i32 range become null.
+fn robj_to_date_series(x: &Robj, name: &str) -> pl::PolarsResult<pl::Series> {
+    let ca = match x.rtype() {
+        Rtype::Integers => pl::Int32Chunked::from_iter_options(
+            name,
+            x.as_integers()
+                .expect("as matched")
+                .iter()
+                .map(|x| if x.is_na() { None } else { Some(x.0) }),
+        ),
+        _ => {
+            let rdouble: Doubles = x.try_into().expect("as matched");
+            pl::Int32Chunked::from_iter_options(
+                name,
+                rdouble.iter().map(|x| {
+                    let days = x.0.floor();
+                    if x.is_na() || !days.is_finite() || days.abs() > i32::MAX as f64 {
+                        None
+                    } else {
+                        Some(days as i32)
+                    }
+                }),
+            )
+        }
+    };
+    ca.into_series().cast(&pl::DataType::Date)
+}
+
+//get time zone of POSIXct tzone attribute. Like R, only the first element of a longer
+//tzone e.g. c("", "EST", "EDT") is used. NULL, "" and NA all mean naive/local time.
+fn robj_to_tz(x: &Robj) -> pl::PolarsResult<Option<pl::TimeZone>> {
+    let tzone = match x.get_attrib("tzone") {
+        None => return Ok(None),
+        Some(tzone) if tzone.is_null() || tzone.len() == 0 => return Ok(None),
+        Some(tzone) => tzone,
+    };
+    let rstrs: Strings = tzone.clone().try_into().map_err(|_| {
+        pl::PolarsError::ComputeError(polars::error::ErrString::Owned(format!(
+            "POSIXct tzone attribute must be character, not {:?}",
+            tzone.rtype()
+        )))
+    })?;
+    Ok(rstrs
+        .iter()
+        .next()
+        .filter(|rstr| !rstr.is_na() && !rstr.as_str().is_empty())
+        .map(|rstr| rstr.as_str().to_string()))
+}
@@ -74,17 +74,11 @@ pub fn rlazy_csv_reader(
     };
 
     //construct optional Schema parameter for overwrite_dtype
-    let dtv = null_to_opt(overwrite_dtype).map(|x| x.clone());
-    let schema = dtv.map(|some_od| {
-        let fields = some_od.0.iter().map(|(name, dtype)| {
-            if let Some(sname) = name {
-                pl::Field::new(sname, dtype.clone())
-            } else {
-                todo!("missing column name for  dtype not implemented");
-            }
-        });
-        pl::Schema::from(fields)
-    });
+    let schema = match null_to_opt(overwrite_dtype).map(csv_overwrite_schema) {
+        Some(Err(err)) => return r_result_list(Err::<(), String>(err)),
+        Some(Ok(schema)) => Some(schema),
+        None => None,
+    };
 
     //construct optional RowCount parameter
     let row_count = null_to_opt(row_count_name).map(|name| polars::io::RowCount {
@@ -116,6 +110,42 @@ pub fn rlazy_csv_reader(
     r_result_list(result)
 }
 
+//dtypes the csv reader can parse a column into, other dtypes error deep in the reader
+fn is_csv_supported_dtype(dtype: &pl::DataType) -> bool {
+    use pl::DataType::*;
+    matches!(
+        dtype,
+        Boolean
+            | Int32
+            | Int64
+            | UInt32
+            | UInt64
+            | Float32
+            | Float64
+            | Utf8
+            | Date
+            | Datetime(_, _)
+            | Categorical(_)
+    )
+}
+
+//validate overwrite_dtype and convert to a Schema, naming any offending column
+fn csv_overwrite_schema(dtv: &DataTypeVector) -> Result<pl::Schema, String> {
+    let fields = dtv.0.iter().map(|(name, dtype)| {
+        let name = name
+            .as_ref()
+            .ok_or_else(|| format!("overwrite_dtype: dtype [{}] has no column name", dtype))?;
+        if !is_csv_supported_dtype(dtype) {
+            return Err(format!(
+                "overwrite_dtype: column [{}] has dtype [{}], which is not supported by the csv reader",
+                name, dtype
+            ));
+        }
+        Ok(pl::Field::new(name, dtype.clone()))
+    });
+    Ok(pl::Schema::from(fields.collect::<Result<Vec<_>, String>>()?.into_iter()))
+}
+
 extendr_module! {
     mod read_csv;
     fn rlazy_csv_reader;
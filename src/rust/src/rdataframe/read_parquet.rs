@@ -1,4 +1,5 @@
-use crate::utils::r_result_list;
+use crate::rdatatype::new_parallel_strategy;
+use crate::utils::{r_error_list, r_result_list};
 
 use crate::rlazyframe::LazyFrame;
 
@@ -19,9 +20,9 @@ pub fn new_from_parquet(
     row_count: u32,
     low_memory: bool,
 ) -> List {
-    let parallel_strategy = match parallel {
-        x if x == "Auto" => pl::ParallelStrategy::Auto,
-        _ => panic!("not implemented"),
+    let parallel_strategy = match new_parallel_strategy(parallel.as_str()) {
+        Ok(ps) => ps,
+        Err(err) => return r_error_list(format!("in scan_parquet: {}", err)),
     };
 
     let row_name = null_to_opt(row_name);
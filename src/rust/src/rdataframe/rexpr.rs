@@ -1,6 +1,10 @@
+use super::r_to_series::robjname2series;
 use super::rseries::Series;
 use crate::rdatatype::literal_to_any_value;
+use crate::rdatatype::new_ewm_options;
 use crate::rdatatype::new_null_behavior;
+use crate::rdatatype::{new_boolean_agg, BooleanAgg};
+use crate::rdatatype::{apply_name_strategy, new_name_strategy};
 use crate::rdatatype::new_quantile_interpolation_option;
 use crate::rdatatype::new_rank_method;
 use crate::rdatatype::{DataType, DataTypeVector};
@@ -140,6 +144,30 @@ impl Expr {
         r_result_list(expr_result)
     }
 
+    //literal from an R scalar of any class supported by Series conversion, e.g. Date or factor.
+    //An NA scalar becomes a null literal of the detected dtype.
+    pub fn lit_from_robj(robj: Robj) -> List {
+        let expr_result = if robj.len() != 1 {
+            Err(format!(
+                "$lit(val), literals must have length one, not length: {:?}",
+                robj.len()
+            ))
+        } else {
+            robjname2series(&robj, "literal")
+                .map(|s| {
+                    if s.null_count() == 1 {
+                        dsl::lit(pl::NULL).cast(s.dtype().clone())
+                    } else {
+                        dsl::lit(s)
+                    }
+                })
+                .map_err(|err| format!("$lit(val): {}", err))
+        }
+        .map(Expr);
+
+        r_result_list(expr_result)
+    }
+
     //expr binary comparisons
     pub fn gt(&self, other: &Expr) -> Self {
         self.0.clone().gt(other.0.clone()).into()
@@ -204,6 +232,14 @@ impl Expr {
         .into()
     }
 
+    pub fn cast_with_strictness(&self, data_type: &DataType, strictness: &str) -> List {
+        use crate::rdatatype::new_cast_strictness;
+        let res = new_cast_strictness(strictness)
+            .map(|strict| self.cast(data_type, strict))
+            .map_err(|err| format!("in cast: {}", err));
+        r_result_list(res)
+    }
+
     pub fn sort(&self, descending: bool, nulls_last: bool) -> Self {
         self.clone()
             .0
@@ -371,6 +407,13 @@ impl Expr {
         r_result_list(res)
     }
 
+    pub fn quantile_f64(&self, quantile: f64, interpolation: &str) -> List {
+        let res = make_quantile_expr(self.0.clone(), quantile, interpolation)
+            .map(Expr)
+            .map_err(|err| format!("in quantile: {}", err));
+        r_result_list(res)
+    }
+
     pub fn filter(&self, predicate: &Expr) -> Expr {
         self.clone().0.filter(predicate.0.clone()).into()
     }
@@ -602,21 +645,21 @@ impl Expr {
         by_null: Nullable<String>,
         closed_null: Nullable<String>,
     ) -> List {
-        let expr = make_rolling_options(
+        let expr = make_rolling_quantile_options(
             window_size,
+            interpolation,
             weights_robj,
             min_periods_float,
             center,
             by_null,
             closed_null,
         )
-        .and_then(|opts| {
-            let interpolation = new_quantile_interpolation_option(interpolation)?;
-            Ok(Expr(self.0.clone().rolling_quantile(
+        .map(|opts| {
+            Expr(self.0.clone().rolling_quantile(
                 quantile,
-                interpolation,
-                opts,
-            )))
+                opts.interpolation,
+                opts.window,
+            ))
         })
         .map_err(|err| format!("rolling_quantile: {}", err));
         r_result_list(expr)
@@ -639,12 +682,16 @@ impl Expr {
 
     fn rank(&self, method: &str, reverse: bool) -> List {
         let expr_res = new_rank_method(method)
-            .map(|rank_method| {
+            .map(|(rank_method, ties_last)| {
                 let options = pl::RankOptions {
                     method: rank_method,
                     descending: reverse,
                 };
-                Expr(self.0.clone().rank(options))
+                if ties_last {
+                    Expr(self.0.clone().reverse().rank(options).reverse())
+                } else {
+                    Expr(self.0.clone().rank(options))
+                }
             })
             .map_err(|err| format!("rank: {}", err));
 
@@ -826,45 +873,71 @@ impl Expr {
         r_result_list(expr_result)
     }
 
-    pub fn ewm_mean(&self, alpha: f64, adjust: bool, min_periods: f64) -> List {
-        let expr_result = || -> std::result::Result<Expr, String> {
-            let min_periods = try_f64_into_usize(min_periods, false)?;
-            let options = pl::EWMOptions {
-                alpha,
-                adjust,
-                bias: false,
-                min_periods,
-            };
-            Ok(self.0.clone().ewm_mean(options).into())
-        }();
+    pub fn ewm_mean(
+        &self,
+        com: Nullable<f64>,
+        span: Nullable<f64>,
+        half_life: Nullable<f64>,
+        alpha: Nullable<f64>,
+        adjust: bool,
+        min_periods: f64,
+    ) -> List {
+        let expr_result = new_ewm_options(
+            null_to_opt(com),
+            null_to_opt(span),
+            null_to_opt(half_life),
+            null_to_opt(alpha),
+            adjust,
+            false,
+            min_periods,
+        )
+        .map(|options| Expr(self.0.clone().ewm_mean(options)));
         r_result_list(expr_result)
     }
 
-    pub fn ewm_std(&self, alpha: f64, adjust: bool, bias: bool, min_periods: f64) -> List {
-        let expr_result = || -> std::result::Result<Expr, String> {
-            let min_periods = try_f64_into_usize(min_periods, false)?;
-            let options = pl::EWMOptions {
-                alpha,
-                adjust,
-                bias,
-                min_periods,
-            };
-            Ok(self.0.clone().ewm_std(options).into())
-        }();
+    pub fn ewm_std(
+        &self,
+        com: Nullable<f64>,
+        span: Nullable<f64>,
+        half_life: Nullable<f64>,
+        alpha: Nullable<f64>,
+        adjust: bool,
+        bias: bool,
+        min_periods: f64,
+    ) -> List {
+        let expr_result = new_ewm_options(
+            null_to_opt(com),
+            null_to_opt(span),
+            null_to_opt(half_life),
+            null_to_opt(alpha),
+            adjust,
+            bias,
+            min_periods,
+        )
+        .map(|options| Expr(self.0.clone().ewm_std(options)));
         r_result_list(expr_result)
     }
 
-    pub fn ewm_var(&self, alpha: f64, adjust: bool, bias: bool, min_periods: f64) -> List {
-        let expr_result = || -> std::result::Result<Expr, String> {
-            let min_periods = try_f64_into_usize(min_periods, false)?;
-            let options = pl::EWMOptions {
-                alpha,
-                adjust,
-                bias,
-                min_periods,
-            };
-            Ok(self.0.clone().ewm_var(options).into())
-        }();
+    pub fn ewm_var(
+        &self,
+        com: Nullable<f64>,
+        span: Nullable<f64>,
+        half_life: Nullable<f64>,
+        alpha: Nullable<f64>,
+        adjust: bool,
+        bias: bool,
+        min_periods: f64,
+    ) -> List {
+        let expr_result = new_ewm_options(
+            null_to_opt(com),
+            null_to_opt(span),
+            null_to_opt(half_life),
+            null_to_opt(alpha),
+            adjust,
+            bias,
+            min_periods,
+        )
+        .map(|options| Expr(self.0.clone().ewm_var(options)));
         r_result_list(expr_result)
     }
 
@@ -1218,6 +1291,13 @@ impl Expr {
         self.0.clone().any().into()
     }
 
+    pub fn boolean_agg(&self, agg: &str, ignore_nulls: Robj) -> List {
+        let res = make_boolean_agg_expr(self.0.clone(), agg, &ignore_nulls)
+            .map(Expr)
+            .map_err(|err| format!("in {}(): {}", agg, err));
+        r_result_list(res)
+    }
+
     pub fn count(&self) -> Self {
         self.0.clone().count().into()
     }
@@ -1383,6 +1463,12 @@ impl Expr {
         self.0.clone().prefix(prefix.as_str()).into()
     }
 
+    fn name_strategy(&self, strategy: &str, affix: &str) -> List {
+        let expr = new_name_strategy(strategy)
+            .map(|strategy| Expr(apply_name_strategy(self.0.clone(), strategy, affix)));
+        r_result_list(expr)
+    }
+
     // fn to_field(&self, df: &DataFrame) {
     //     let ctxt = polars::prelude::Context::Default;
     //     let res = self.0.to_field(&df.0.schema(), ctxt);
@@ -1487,7 +1573,7 @@ pub fn make_rolling_options(
         .transpose()?;
 
     Ok(pl::RollingOptions {
-        window_size: pl::Duration::parse(window_size),
+        window_size: parse_window_duration(window_size)?,
         weights,
         min_periods,
         center,
@@ -1496,6 +1582,172 @@ pub fn make_rolling_options(
     })
 }
 
+//polars Duration::parse panics on malformed input, check first it is only pairs of an integer
+//and a unit e.g. "7d", "1h30m" or "3i"
+pub fn parse_window_duration(s: &str) -> std::result::Result<pl::Duration, String> {
+    const UNITS: [&str; 11] = ["ns", "us", "ms", "mo", "s", "m", "h", "d", "w", "y", "i"];
+    let mut rest = s.strip_prefix('-').unwrap_or(s);
+    if rest.is_empty() {
+        return Err(format!("window duration [{}] is empty", s));
+    }
+    while !rest.is_empty() {
+        let n_digits = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+        let unit = UNITS
+            .iter()
+            .find(|unit| n_digits > 0 && rest[n_digits..].starts_with(*unit))
+            .ok_or_else(|| {
+                format!(
+                    "window duration [{}] is not integer and unit pairs, e.g. '7d' or '1h30m', \
+                    units are any of 'ns', 'us', 'ms', 's', 'm', 'h', 'd', 'w', 'mo', 'y' or 'i'",
+                    s
+                )
+            })?;
+        rest = &rest[n_digits + unit.len()..];
+    }
+    Ok(pl::Duration::parse(s))
+}
+
+//make dynamic groupby options from R friendly arguments, validating all durations. A NULL period
+//is every, a NULL offset is minus every or, with a given period, zero.
+#[allow(clippy::too_many_arguments)]
+pub fn make_dynamic_group_options(
+    index_column: &str,
+    every: &str,
+    period_null: Nullable<String>,
+    offset_null: Nullable<String>,
+    closed: &str,
+    label: &str,
+    include_boundaries: bool,
+    start_by: &str,
+) -> std::result::Result<pl::DynamicGroupOptions, String> {
+    use crate::rdatatype::new_closed_window;
+
+    let parse = |arg: &str, s: &str| {
+        parse_window_duration(s).map_err(|err| format!("dynamic groupby {}: {}", arg, err))
+    };
+    let every_duration = parse("every", every)?;
+    let period_null = null_to_opt(period_null);
+    let offset = match null_to_opt(offset_null) {
+        Some(offset) => parse("offset", &offset)?,
+        None if period_null.is_some() => pl::Duration::parse("0ns"),
+        None => parse("offset", &format!("-{}", every.strip_prefix('-').unwrap_or(every)))?,
+    };
+    let period = match period_null {
+        Some(period) => parse("period", &period)?,
+        None => every_duration,
+    };
+
+    //polars of this build labels windows by their start or by the first datapoint (truncate)
+    let truncate = match label {
+        "left" => true,
+        "datapoint" => false,
+        "right" => {
+            return Err(
+                "dynamic groupby label [right] is not available in the polars version of this build"
+                    .into(),
+            )
+        }
+        _ => {
+            return Err(format!(
+                "dynamic groupby label: [{}] is not any of 'left', 'right' or 'datapoint'",
+                label
+            ))
+        }
+    };
+    //windows always start at the earliest time point, no other StartBy exists in this build
+    if start_by != "window" {
+        return Err(format!(
+            "dynamic groupby start_by: [{}] is not available in the polars version of this build, only 'window'",
+            start_by
+        ));
+    }
+
+    Ok(pl::DynamicGroupOptions {
+        index_column: index_column.into(),
+        every: every_duration,
+        period,
+        offset,
+        truncate,
+        include_boundaries,
+        closed_window: new_closed_window(closed)?,
+    })
+}
+
+pub struct RollingQuantileOptions {
+    pub window: pl::RollingOptions,
+    pub interpolation: pl::QuantileInterpolOptions,
+}
+
+//make rolling options plus quantile interpolation, validating both
+pub fn make_rolling_quantile_options(
+    window_size: &str,
+    interpolation: &str,
+    weights_robj: Nullable<Vec<f64>>,
+    min_periods_float: f64,
+    center: bool,
+    by_null: Nullable<String>,
+    closed_null: Nullable<String>,
+) -> std::result::Result<RollingQuantileOptions, String> {
+    Ok(RollingQuantileOptions {
+        window: make_rolling_options(
+            window_size,
+            weights_robj,
+            min_periods_float,
+            center,
+            by_null,
+            closed_null,
+        )?,
+        interpolation: new_quantile_interpolation_option(interpolation)?,
+    })
+}
+
+//make quantile aggregation from a plain probability, handle out of range and interpolation errors
+pub fn make_quantile_expr(
+    expr: pl::Expr,
+    quantile: f64,
+    interpolation: &str,
+) -> std::result::Result<pl::Expr, String> {
+    if !(0.0..=1.0).contains(&quantile) {
+        return Err(format!(
+            "quantile probability {} is not within 0.0 and 1.0",
+            quantile
+        ));
+    }
+    let intpl = new_quantile_interpolation_option(interpolation)?;
+    Ok(expr.quantile(dsl::lit(quantile), intpl))
+}
+
+//make any/all aggregation. If ignore_nulls, nulls are skipped. If not, Kleene logic applies:
+//a null is unknown, so any() is null without any true but some null, and all() is null without
+//any false but some null.
+pub fn make_boolean_agg_expr(
+    expr: pl::Expr,
+    agg: &str,
+    ignore_nulls: &Robj,
+) -> std::result::Result<pl::Expr, String> {
+    let agg = new_boolean_agg(agg)?;
+    let ignore_nulls = match (ignore_nulls.as_logical_slice(), ignore_nulls.len()) {
+        (Some(&[b]), 1) if !b.is_na() => b.is_true(),
+        _ => return Err("ignore_nulls must be TRUE or FALSE".into()),
+    };
+    let null = || dsl::lit(pl::NULL).cast(pl::DataType::Boolean);
+    let any_null = expr.clone().is_null().any();
+    Ok(match (agg, ignore_nulls) {
+        (BooleanAgg::Any, true) => expr.any(),
+        (BooleanAgg::All, true) => expr.all(),
+        (BooleanAgg::Any, false) => dsl::when(expr.any())
+            .then(dsl::lit(true))
+            .when(any_null)
+            .then(null())
+            .otherwise(dsl::lit(false)),
+        (BooleanAgg::All, false) => dsl::when(expr.not().any())
+            .then(dsl::lit(false))
+            .when(any_null)
+            .then(null())
+            .otherwise(dsl::lit(true)),
+    })
+}
+
 extendr_module! {
     mod rexpr;
     impl Expr;
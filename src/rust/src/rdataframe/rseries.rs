@@ -13,7 +13,9 @@ use crate::utils::{r_error_list, r_ok_list, r_result_list};
 use super::DataFrame;
 use crate::utils::wrappers::null_to_opt;
 
-use crate::rdataframe::r_to_series::robjname2series;
+use crate::rdataframe::r_to_series::{
+    robjname2series, robjname2series_with_dtype, robjname2series_with_options, ConversionOptions,
+};
 use crate::rdataframe::series_to_r::pl_series_to_list;
 use crate::utils::try_f64_into_usize;
 use extendr_api::{extendr, prelude::*, rprintln, Rinternals};
@@ -61,6 +63,24 @@ impl Series {
         }
     }
 
+    pub fn new_with_options(x: Robj, name: &str, options: List) -> List {
+        let s_res = ConversionOptions::from_rlist(&options)
+            .map_err(|err| {
+                pl::PolarsError::InvalidOperation(polars::error::ErrString::Owned(err))
+            })
+            .and_then(|opts| robjname2series_with_options(&x, name, &opts));
+        r_result_list(s_res.map(Series))
+    }
+
+    pub fn new_with_dtype(x: Robj, name: &str, options: List, dtype: &DataType) -> List {
+        let s_res = ConversionOptions::from_rlist(&options)
+            .map_err(|err| {
+                pl::PolarsError::InvalidOperation(polars::error::ErrString::Owned(err))
+            })
+            .and_then(|opts| robjname2series_with_dtype(&x, name, &opts, &dtype.0));
+        r_result_list(s_res.map(Series))
+    }
+
     pub fn clone(&self) -> Series {
         Series(self.0.clone())
     }
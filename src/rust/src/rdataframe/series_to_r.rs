@@ -38,6 +38,17 @@ pub fn pl_series_to_list(series: &pl::Series, tag_structs: bool) -> pl::PolarsRe
                     .collect_robj()
             }),
             Utf8 => s.utf8().map(|ca| ca.into_iter().collect_robj()),
+            //each Binary value is exported as an R raw vector, null as NULL
+            Binary => s.binary().map(|ca| {
+                let v: Vec<Robj> = ca
+                    .into_iter()
+                    .map(|opt| match opt {
+                        Some(bytes) => Raw::from_bytes(bytes).into_robj(),
+                        None => r!(extendr_api::NULL),
+                    })
+                    .collect();
+                extendr_api::List::from_values(v).into_robj()
+            }),
 
             Boolean => s.bool().map(|ca| ca.into_iter().collect_robj()),
             Categorical(_) => s
@@ -1,5 +1,5 @@
 use crate::utils::r_result_list;
-use crate::utils::wrappers::Wrap;
+use crate::utils::wrappers::{null_to_opt, Wrap};
 use extendr_api::prelude::*;
 use polars::prelude::{self as pl};
 use polars_core::prelude::QuantileInterpolOptions;
@@ -11,76 +11,149 @@ pub struct DataType(pub pl::DataType);
 #[extendr]
 impl DataType {
     pub fn new(s: &str) -> DataType {
-        //, inner: Nullable<&DataType>
-        //let inner = Box::new(null_to_opt(inner).map_or(pl::DataType::Null, |x| x.0.clone()));
-
-        let pl_datatype = match s {
-            "Boolean" | "logical" => pl::DataType::Boolean,
-            "UInt8" | "uinteger8" => pl::DataType::UInt8,
-            "UInt16" | "uinteger16" => pl::DataType::UInt16,
-            "UInt32" | "uinteger32" => pl::DataType::UInt32,
-            "UInt64" | "uinteger64" => pl::DataType::UInt64,
-            "Int8" | "integer8" => pl::DataType::Int8,
-            "Int16" | "integer16" => pl::DataType::Int16,
-            "Int32" | "integer32" | "integer" => pl::DataType::Int32,
-            "Int64" | "integer64" => pl::DataType::Int64,
-            "Float32" | "float32" | "double" => pl::DataType::Float32,
-            "Float64" | "float64" => pl::DataType::Float64,
-
-            "Utf8" | "character" => pl::DataType::Utf8,
-            "Binary" | "binary" => pl::DataType::Binary,
-            "Date" | "date" => pl::DataType::Date,
-            "Time" | "time" => pl::DataType::Time,
-            "Null" | "null" => pl::DataType::Null,
-            "Categorical" | "factor" => pl::DataType::Categorical(None),
-            "Unknown" | "unknown" => pl::DataType::Unknown,
-
-            _ => panic!("data type not recgnized"),
-        };
-        DataType(pl_datatype)
+        DataType::try_new_inner(s).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    //as new, but an unrecognized name is an error, not a panic
+    pub fn try_new(s: &str) -> List {
+        r_result_list(DataType::try_new_inner(s))
     }
 
-    pub fn new_datetime() -> DataType {
-        todo!("datetime not implemented")
+    pub fn new_datetime(tu: &str, tz: Nullable<String>) -> List {
+        let dt_result = new_time_unit(tu)
+            .map(|tu| DataType(pl::DataType::Datetime(tu, null_to_opt(tz))));
+        r_result_list(dt_result)
     }
 
-    pub fn new_duration() -> DataType {
-        todo!("duration not implemented")
+    pub fn new_duration(tu: &str) -> List {
+        r_result_list(new_time_unit(tu).map(|tu| DataType(pl::DataType::Duration(tu))))
     }
 
     pub fn new_list(inner: &DataType) -> DataType {
         DataType(pl::DataType::List(Box::new(inner.0.clone())))
     }
 
+    //as new_list, but a List(Unknown) is an error unless allow_unknown. An allowed List(Unknown)
+    //declares a list, where the inner DataType is inferred from data.
+    pub fn new_list_checked(inner: &DataType, allow_unknown: bool) -> List {
+        let res = if !allow_unknown && contains_unknown(&inner.0) {
+            Err(format!(
+                "List inner DataType {} is or contains Unknown, set allow_unknown to infer it from data",
+                inner.0
+            ))
+        } else {
+            Ok(DataType::new_list(inner))
+        };
+        r_result_list(res)
+    }
+
+    //build Struct from a named R list of DataTypes
+    pub fn new_struct_from_rlist(list: List) -> List {
+        let fields: std::result::Result<Vec<pl::Field>, String> = list
+            .iter()
+            .enumerate()
+            .map(|(i, (name, robj))| {
+                if name.is_empty() {
+                    return Err(format!("struct field at position {} has no name", i + 1));
+                }
+                robj_to_datatype(&robj).map(|dt| pl::Field::new(name, dt.0))
+            })
+            .collect();
+        r_result_list(fields.and_then(new_struct_dtype))
+    }
+
+    //derive Struct from an R list of Series, without building the struct series. A field is
+    //named by the list name, or if unnamed by the Series name.
+    pub fn new_struct_from_series(series: List) -> List {
+        use crate::rdataframe::rseries::Series;
+        let fields: std::result::Result<Vec<pl::Field>, String> = series
+            .iter()
+            .enumerate()
+            .map(|(i, (name, robj))| {
+                let s = Series::inner_from_robj_clone(&robj)
+                    .map_err(|err| format!("struct field at position {}: {}", i + 1, err))?;
+                let name = if name.is_empty() { s.0.name() } else { name };
+                if name.is_empty() {
+                    return Err(format!("struct field at position {} has no name", i + 1));
+                }
+                Ok(pl::Field::new(name, s.0.dtype().clone()))
+            })
+            .collect();
+        r_result_list(fields.and_then(new_struct_dtype))
+    }
+
     pub fn new_object() -> DataType {
         todo!("object not implemented")
     }
 
-    pub fn new_struct() -> DataType {
-        todo!("struct not implemented")
+    //common result DataType of when/then/otherwise branches, given as an R list of DataType's.
+    //Stricter than supertype, see strict_supertype.
+    pub fn when_then_supertype(branches: List) -> List {
+        let dtypes: std::result::Result<Vec<pl::DataType>, String> = branches
+            .iter()
+            .map(|(_, robj)| robj_to_datatype(&robj).map(|dt| dt.0))
+            .collect();
+        r_result_list(dtypes.and_then(|dtypes| {
+            let mut iter = dtypes.iter().enumerate();
+            let (_, first) = iter
+                .next()
+                .ok_or_else(|| "when/then needs at least one branch".to_string())?;
+            iter.try_fold(first.clone(), |acc, (i, dt)| {
+                strict_supertype(&acc, dt).ok_or_else(|| {
+                    format!(
+                        "when/then branch {} of DataType {} is not compatible with previous branches of DataType {}",
+                        i + 1,
+                        dt,
+                        acc
+                    )
+                })
+            })
+        }).map(DataType))
+    }
+
+    //common supertype of an R list of DataType's, as DataTypeVector supertype. Ok NULL if the list
+    //is empty or any DataType has no supertype with the previous ones.
+    pub fn supertype_of_many(dtypes: List) -> List {
+        let dtv: std::result::Result<Vec<(Option<String>, pl::DataType)>, String> = dtypes
+            .iter()
+            .map(|(_, robj)| robj_to_datatype(&robj).map(|dt| (None, dt.0)))
+            .collect();
+        r_result_list(dtv.map(|dtv| match DataTypeVector(dtv).try_supertype() {
+            Ok(dt) => DataType(dt).into_robj(),
+            Err(_) => Robj::from(()),
+        }))
+    }
+
+    //build Struct from an R list of Field's
+    pub fn new_struct(fields: List) -> List {
+        let fields: std::result::Result<Vec<pl::Field>, String> = fields
+            .iter()
+            .map(|(_, robj)| robj_to_field(&robj).map(|f| f.0))
+            .collect();
+        r_result_list(fields.and_then(new_struct_dtype))
     }
 
     pub fn get_all_simple_type_names() -> Vec<String> {
-        vec![
-            "Boolean".into(),
-            "UInt8".into(),
-            "UInt16".into(),
-            "UInt32".into(),
-            "UInt64".into(),
-            "Int8".into(),
-            "Int16".into(),
-            "Int32".into(),
-            "Int64".into(),
-            "Float32".into(),
-            "Float64".into(),
-            "Utf8".into(),
-            "Binary".into(),
-            "Date".into(),
-            "Time".into(),
-            "Null".into(),
-            "Categorical".into(),
-            "Unknown".into(),
-        ]
+        DATATYPE_ALIASES
+            .iter()
+            .filter(|(_, _, listed)| *listed)
+            .map(|(alias, _, _)| alias.to_string())
+            .collect()
+    }
+
+    //every name DataType::new resolves to this DataType, polars name first. Empty for a
+    //DataType without a name, e.g. a List or a Datetime with time zone.
+    pub fn accepted_aliases(&self) -> Vec<String> {
+        //the categories of a Categorical do not matter to its name
+        let dt = match &self.0 {
+            pl::DataType::Categorical(_) => pl::DataType::Categorical(None),
+            dt => dt.clone(),
+        };
+        DATATYPE_ALIASES
+            .iter()
+            .filter(|(_, alias_dt, _)| *alias_dt == dt)
+            .map(|(alias, _, _)| alias.to_string())
+            .collect()
     }
 
     pub fn print(&self) {
@@ -94,14 +167,377 @@ impl DataType {
     pub fn ne(&self, other: &DataType) -> bool {
         self.0.ne(&other.0)
     }
+
+    //describe first found difference, Null if equal
+    pub fn diff(&self, other: &DataType) -> Nullable<String> {
+        match dtype_diff(&self.0, &other.0, "") {
+            Some(description) => Nullable::NotNull(description),
+            None => Nullable::Null,
+        }
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.is_signed_integer() || self.is_unsigned_integer()
+    }
+
+    pub fn is_signed_integer(&self) -> bool {
+        use pl::DataType::*;
+        matches!(self.0, Int8 | Int16 | Int32 | Int64)
+    }
+
+    pub fn is_unsigned_integer(&self) -> bool {
+        use pl::DataType::*;
+        matches!(self.0, UInt8 | UInt16 | UInt32 | UInt64)
+    }
+
+    pub fn is_float(&self) -> bool {
+        use pl::DataType::*;
+        matches!(self.0, Float32 | Float64)
+    }
+
+    //rank in numeric widening order, where UIntN ranks just below IntN as it only widens
+    //losslessly to a wider signed integer, see widening_cmp for comparisons crossing signedness.
+    //Null for types with no numeric widening order e.g. Utf8, List
+    pub fn widening_rank(&self) -> Nullable<i32> {
+        match widening_rank(&self.0) {
+            Some(rank) => Nullable::NotNull(rank),
+            None => Nullable::Null,
+        }
+    }
+
+    //-1, 0 or 1 as self widens to, equals or is widened to by other. Null if either has no
+    //widening rank, or if a signed and an unsigned integer do not widen losslessly either way,
+    //e.g. UInt64 and Int64 or Int8 and UInt16.
+    pub fn widening_cmp(&self, other: &DataType) -> Nullable<i32> {
+        let (r1, r2) = match (widening_rank(&self.0), widening_rank(&other.0)) {
+            (Some(r1), Some(r2)) => (r1, r2),
+            _ => return Nullable::Null,
+        };
+        let sign_crossing = |unsigned: &DataType, signed: &DataType| {
+            unsigned.is_unsigned_integer() && signed.is_signed_integer()
+        };
+        //UIntN widens only to Int(2N) or wider, which ranks at least 2 above. No signed integer
+        //widens to any unsigned one.
+        let lossless = if sign_crossing(self, other) {
+            r2 - r1 > 1
+        } else if sign_crossing(other, self) {
+            r1 - r2 > 1
+        } else {
+            true
+        };
+        if lossless {
+            Nullable::NotNull(r1.cmp(&r2) as i32)
+        } else {
+            Nullable::Null
+        }
+    }
+
+    //named list of field DataTypes in struct order, error if not a Struct
+    pub fn struct_fields(&self) -> List {
+        let res = match &self.0 {
+            pl::DataType::Struct(fields) => {
+                let names: Vec<&str> = fields.iter().map(|f| f.name().as_str()).collect();
+                let dtypes: Vec<Robj> = fields
+                    .iter()
+                    .map(|f| DataType(f.data_type().clone()).into_robj())
+                    .collect();
+                Ok(List::from_names_and_values(names, dtypes).expect("same length of names and values"))
+            }
+            dt => Err(format!("DataType {:?} is not a Struct", dt)),
+        };
+        r_result_list(res)
+    }
+
+    //true if exporting this dtype to R may lose precision, e.g. 64-bit integers as R doubles
+    pub fn r_export_lossy(&self) -> bool {
+        dtype_r_export_lossy(&self.0)
+    }
+
+    //fixed categories of an Enum, Null for open categoricals. The polars version of this build
+    //has no Enum, and a Categorical is always open, so this is Null for every DataType.
+    pub fn categories(&self) -> Nullable<Vec<String>> {
+        Nullable::Null
+    }
+
+    //polars append/vstack requires identical DataTypes. With promote, other may also differ if
+    //both can be cast to a common supertype, as by when_then_supertype (no implicit Utf8).
+    pub fn append_compatible_with(&self, other: &DataType, promote: bool) -> bool {
+        self.0 == other.0 || (promote && strict_supertype(&self.0, &other.0).is_some())
+    }
+
+    //Null if not a Struct
+    pub fn n_fields(&self) -> Nullable<i32> {
+        match &self.0 {
+            pl::DataType::Struct(fields) => Nullable::NotNull(fields.len() as i32),
+            _ => Nullable::Null,
+        }
+    }
+
+    //leaf DataTypes of nested Structs, named by their field path joined by sep. Ok NULL if not a
+    //Struct, error if any leaf is Unknown, as for any DataTypeVector schema.
+    pub fn flatten_struct(&self, sep: &str) -> List {
+        fn flatten(
+            fields: &[pl::Field],
+            prefix: &str,
+            sep: &str,
+            out: &mut DataTypeVector,
+        ) -> std::result::Result<(), String> {
+            for f in fields {
+                let path = if prefix.is_empty() {
+                    f.name().to_string()
+                } else {
+                    format!("{}{}{}", prefix, sep, f.name())
+                };
+                match f.data_type() {
+                    pl::DataType::Struct(inner) => flatten(inner, &path, sep, out)?,
+                    dt => out.push_checked(Some(path), dt.clone())?,
+                }
+            }
+            Ok(())
+        }
+        let res = match &self.0 {
+            pl::DataType::Struct(fields) => {
+                let mut out = DataTypeVector::new();
+                flatten(fields, "", sep, &mut out).map(|_| out.into_robj())
+            }
+            _ => Ok(Robj::from(())),
+        };
+        r_result_list(res)
+    }
+
+    //Null if not a Struct
+    pub fn field_names(&self) -> Nullable<Vec<String>> {
+        match &self.0 {
+            pl::DataType::Struct(fields) => {
+                Nullable::NotNull(fields.iter().map(|f| f.name().to_string()).collect())
+            }
+            _ => Nullable::Null,
+        }
+    }
+
+    //Null if not a Struct or no field of that name
+    pub fn field_dtype(&self, name: &str) -> Nullable<DataType> {
+        match &self.0 {
+            pl::DataType::Struct(fields) => fields
+                .iter()
+                .find(|f| f.name() == name)
+                .map(|f| Nullable::NotNull(DataType(f.data_type().clone())))
+                .unwrap_or(Nullable::Null),
+            _ => Nullable::Null,
+        }
+    }
+
+    //zero-indexed, error if not a Struct or out of bounds
+    pub fn field_by_index(&self, i: i32) -> List {
+        r_result_list(self.get_field(i).map(|f| DataType(f.data_type().clone())))
+    }
+
+    //zero-indexed, error if not a Struct or out of bounds
+    pub fn field_name_by_index(&self, i: i32) -> List {
+        r_result_list(self.get_field(i).map(|f| f.name().to_string()))
+    }
+
+    //Null if not a Datetime or a naive Datetime
+    pub fn time_zone(&self) -> Nullable<String> {
+        match &self.0 {
+            pl::DataType::Datetime(_, Some(tz)) => Nullable::NotNull(tz.clone()),
+            _ => Nullable::Null,
+        }
+    }
+
+    //Null if not a Datetime or Duration
+    pub fn time_unit(&self) -> Nullable<String> {
+        match &self.0 {
+            pl::DataType::Datetime(tu, _) | pl::DataType::Duration(tu) => Nullable::NotNull(
+                match tu {
+                    pl::TimeUnit::Nanoseconds => "ns",
+                    pl::TimeUnit::Microseconds => "us",
+                    pl::TimeUnit::Milliseconds => "ms",
+                }
+                .to_string(),
+            ),
+            _ => Nullable::Null,
+        }
+    }
 }
 
+impl DataType {
+    fn get_field(&self, i: i32) -> std::result::Result<&pl::Field, String> {
+        let fields = match &self.0 {
+            pl::DataType::Struct(fields) => fields,
+            dt => return Err(format!("DataType {} is not a Struct, it has no fields", dt)),
+        };
+        usize::try_from(i)
+            .ok()
+            .and_then(|idx| fields.get(idx))
+            .ok_or_else(|| {
+                format!(
+                    "field index {} is out of bounds for Struct of {} fields",
+                    i,
+                    fields.len()
+                )
+            })
+    }
+
+    fn try_new_inner(s: &str) -> std::result::Result<DataType, String> {
+        if s.trim().is_empty() {
+            return Err(format!(
+                "DataType name [{}] is blank, see get_all_simple_type_names() for valid names",
+                s
+            ));
+        }
+        if let Some((_, dt, _)) = DATATYPE_ALIASES.iter().find(|(alias, _, _)| *alias == s) {
+            return Ok(DataType(dt.clone()));
+        }
+        match s {
+            //arrow names without an equal polars type parse to the closest one, but are no alias
+            //of it, see accepted_aliases. date64 (ms since epoch) becomes Datetime ms, timestamp
+            //defaults to us as Datetime.
+            "date64" => Ok(DataType(pl::DataType::Datetime(
+                pl::TimeUnit::Milliseconds,
+                None,
+            ))),
+            "timestamp" => Ok(DataType(pl::DataType::Datetime(
+                pl::TimeUnit::Microseconds,
+                None,
+            ))),
+            //string views came with later polars versions
+            "Utf8View" | "utf8_view" | "string_view" | "BinaryView" | "binary_view" => Err(format!(
+                "DataType [{}] is not available in the polars version of this build, use Utf8 or Binary",
+                s
+            )),
+            //polars has no half-precision float
+            "Float16" | "float16" | "half" => Err(format!(
+                "DataType [{}] is not available in the polars version of this build, use Float32",
+                s
+            )),
+            _ => Err(format!("DataType name [{}] is not recognized", s)),
+        }
+    }
+}
+
+// Every DataType name accepted by DataType::new, the DataType it resolves to and whether it is
+// listed by get_all_simple_type_names, i.e. exported as pl$<name>. Only polars names are listed,
+// aliases only parse. Aliases of one DataType are kept together, the polars name first. Besides
+// polars names there are R style names, arrow names and the short Rust names i8 .. i64,
+// u8 .. u64, f32 and f64. The single source of parsing, listing names and the aliases of a
+// DataType, see accepted_aliases.
+const DATATYPE_ALIASES: [(&str, pl::DataType, bool); 57] = [
+    ("Boolean", pl::DataType::Boolean, true),
+    ("logical", pl::DataType::Boolean, false),
+    ("bool", pl::DataType::Boolean, false),
+    ("lgl", pl::DataType::Boolean, false),
+    ("UInt8", pl::DataType::UInt8, true),
+    ("uinteger8", pl::DataType::UInt8, false),
+    ("u8", pl::DataType::UInt8, false),
+    ("UInt16", pl::DataType::UInt16, true),
+    ("uinteger16", pl::DataType::UInt16, false),
+    ("u16", pl::DataType::UInt16, false),
+    ("UInt32", pl::DataType::UInt32, true),
+    ("uinteger32", pl::DataType::UInt32, false),
+    ("u32", pl::DataType::UInt32, false),
+    ("UInt64", pl::DataType::UInt64, true),
+    ("uinteger64", pl::DataType::UInt64, false),
+    ("u64", pl::DataType::UInt64, false),
+    ("Int8", pl::DataType::Int8, true),
+    ("integer8", pl::DataType::Int8, false),
+    ("i8", pl::DataType::Int8, false),
+    ("Int16", pl::DataType::Int16, true),
+    ("integer16", pl::DataType::Int16, false),
+    ("i16", pl::DataType::Int16, false),
+    ("Int32", pl::DataType::Int32, true),
+    ("integer32", pl::DataType::Int32, false),
+    ("integer", pl::DataType::Int32, false),
+    ("i32", pl::DataType::Int32, false),
+    ("Int64", pl::DataType::Int64, true),
+    ("integer64", pl::DataType::Int64, false),
+    ("i64", pl::DataType::Int64, false),
+    ("Float32", pl::DataType::Float32, true),
+    ("float32", pl::DataType::Float32, false),
+    ("f32", pl::DataType::Float32, false),
+    ("Float64", pl::DataType::Float64, true),
+    ("float64", pl::DataType::Float64, false),
+    //an R double is 64 bit
+    ("double", pl::DataType::Float64, false),
+    ("f64", pl::DataType::Float64, false),
+    ("Utf8", pl::DataType::Utf8, true),
+    ("character", pl::DataType::Utf8, false),
+    ("String", pl::DataType::Utf8, false),
+    ("str", pl::DataType::Utf8, false),
+    //polars Utf8 is physically arrow large_utf8 already
+    ("LargeUtf8", pl::DataType::Utf8, false),
+    ("large_utf8", pl::DataType::Utf8, false),
+    ("large_string", pl::DataType::Utf8, false),
+    ("Binary", pl::DataType::Binary, true),
+    ("binary", pl::DataType::Binary, false),
+    ("Date", pl::DataType::Date, true),
+    ("date", pl::DataType::Date, false),
+    //arrow date32 is days as Date
+    ("date32", pl::DataType::Date, false),
+    ("Time", pl::DataType::Time, true),
+    ("time", pl::DataType::Time, false),
+    //time64 in arrow has us or ns, Time is always ns
+    ("time64", pl::DataType::Time, false),
+    ("Null", pl::DataType::Null, true),
+    ("null", pl::DataType::Null, false),
+    ("Categorical", pl::DataType::Categorical(None), true),
+    ("factor", pl::DataType::Categorical(None), false),
+    ("Unknown", pl::DataType::Unknown, true),
+    ("unknown", pl::DataType::Unknown, false),
+];
+
 impl From<DataType> for pl::DataType {
     fn from(x: DataType) -> Self {
         x.0
     }
 }
 
+//a named DataType, e.g. to build a Struct
+#[extendr]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field(pub pl::Field);
+
+#[extendr]
+impl Field {
+    pub fn new(name: &str, datatype: &DataType) -> Field {
+        Field(pl::Field::new(name, datatype.0.clone()))
+    }
+
+    pub fn name(&self) -> String {
+        self.0.name().to_string()
+    }
+
+    pub fn datatype(&self) -> DataType {
+        DataType(self.0.data_type().clone())
+    }
+
+    pub fn print(&self) {
+        rprintln!("{:#?}", self.0);
+    }
+
+    pub fn eq(&self, other: &Field) -> bool {
+        self.0.eq(&other.0)
+    }
+}
+
+//fields keep the given order, as polars Struct is positional. Duplicate names are rejected.
+fn new_struct_dtype(fields: Vec<pl::Field>) -> std::result::Result<DataType, String> {
+    for (i, f) in fields.iter().enumerate() {
+        if fields[..i].iter().any(|prev| prev.name() == f.name()) {
+            return Err(format!("struct field name [{}] is duplicated", f.name()));
+        }
+    }
+    Ok(DataType(pl::DataType::Struct(fields)))
+}
+
+pub fn robj_to_field(robj: &Robj) -> std::result::Result<Field, String> {
+    if !robj.inherits("Field") || robj.rtype() != extendr_api::Rtype::ExternalPtr {
+        return Err("Internal error: Object is not a Field".into());
+    }
+    //safety checks class and type before conversion
+    Ok(unsafe { &mut *robj.external_ptr_addr::<Field>() }.clone())
+}
+
 //struct for building a vector of optional named datatype,
 //if all named will become a schema and passed to polars_io.csv.csvread.with_dtypes
 //if any names are missing will become slice of dtypes and passed to polars_io.csv.csvread.with_dtypes_slice
@@ -116,42 +552,289 @@ impl DataTypeVector {
         DataTypeVector(Vec::new())
     }
 
+    //unchecked, see try_push to reject Unknown in a schema
     pub fn push(&mut self, colname: Nullable<String>, datatype: &DataType) {
         self.0.push((Wrap(colname).into(), datatype.clone().into()));
     }
 
+    pub fn try_push(&mut self, colname: Nullable<String>, datatype: &DataType) -> List {
+        r_result_list(self.push_checked(Wrap(colname).into(), datatype.clone().into()))
+    }
+
     pub fn print(&self) {
         rprintln!("{:#?}", self.0);
     }
 
+    pub fn len(&self) -> i32 {
+        self.0.len() as i32
+    }
+
+    //append all entries of other, keeping order and any duplicated names
+    pub fn append_vector(&mut self, other: &DataTypeVector) {
+        self.0.extend(other.0.iter().cloned());
+    }
+
+    //zero-indexed, Null if unnamed or out of bounds
+    pub fn get_name(&self, i: i32) -> Nullable<String> {
+        match self.get_entry(i) {
+            Ok((Some(name), _)) => Nullable::NotNull(name.clone()),
+            _ => Nullable::Null,
+        }
+    }
+
+    //zero-indexed
+    pub fn get_dtype(&self, i: i32) -> List {
+        r_result_list(self.get_entry(i).map(|(_, dt)| DataType(dt.clone())))
+    }
+
     pub fn from_rlist(list: List) -> List {
         let mut dtv = DataTypeVector(Vec::with_capacity(list.len()));
 
         let result: std::result::Result<(), String> = list
             .iter()
             .map(|(name, robj)| -> std::result::Result<(), String> {
-                if !robj.inherits("DataType") || robj.rtype() != extendr_api::Rtype::ExternalPtr {
-                    return Err("Internal error: Object is not a DataType".into());
+                let dt = robj_to_datatype(&robj)?;
+                dtv.push_checked(Some(name.to_string()), dt.0)
+            })
+            .collect();
+
+        r_result_list(result.map(|_| dtv))
+    }
+
+    //schema of every name with the same dtype, e.g. to read all csv columns as Utf8
+    pub fn fill_all(dtype: &DataType, names: Strings) -> List {
+        let mut dtv = DataTypeVector(Vec::with_capacity(names.len()));
+        let result: std::result::Result<(), String> = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| -> std::result::Result<(), String> {
+                if name.is_na() {
+                    return Err(format!("column name at position {} is NA", i + 1));
                 }
-                //safety checks class and type before conversion
-                let dt: DataType = unsafe { &mut *robj.external_ptr_addr::<DataType>() }.clone();
-                let name = extendr_api::Nullable::NotNull(name.to_string());
-                dtv.push(name, &dt);
-                Ok(())
+                dtv.push_checked(Some(name.as_str().to_string()), dtype.0.clone())
             })
             .collect();
 
         r_result_list(result.map(|_| dtv))
     }
+
+    //unnamed entries get an empty name
+    pub fn to_rlist(&self) -> List {
+        let names = self
+            .0
+            .iter()
+            .map(|(name, _)| name.clone().unwrap_or_default());
+        let values = self.0.iter().map(|(_, dt)| DataType(dt.clone()));
+        List::from_names_and_values(names, values).expect("same length of names and values")
+    }
+
+    //common supertype all DataTypes can be cast to, e.g. the value column dtype of a melt
+    pub fn supertype(&self) -> List {
+        r_result_list(self.try_supertype().map(DataType))
+    }
 }
 
 impl DataTypeVector {
+    //a DataTypeVector is used as schema e.g. overwrite_dtype in csv reader, where polars needs
+    //concrete types. Unknown is only legit as a standalone DataType, e.g. to compare with.
+    pub fn push_checked(
+        &mut self,
+        colname: Option<String>,
+        datatype: pl::DataType,
+    ) -> std::result::Result<(), String> {
+        if contains_unknown(&datatype) {
+            return Err(format!(
+                "DataType {} for column [{}] is or contains Unknown, a schema needs a concrete DataType",
+                datatype,
+                colname.as_deref().unwrap_or("<unnamed>"),
+            ));
+        }
+        self.0.push((colname, datatype));
+        Ok(())
+    }
+
+    fn get_entry(&self, i: i32) -> std::result::Result<&(Option<String>, pl::DataType), String> {
+        usize::try_from(i)
+            .ok()
+            .and_then(|idx| self.0.get(idx))
+            .ok_or_else(|| {
+                format!(
+                    "index {} is out of bounds for DataTypeVector of length {}",
+                    i,
+                    self.0.len()
+                )
+            })
+    }
+
+    pub fn try_supertype(&self) -> std::result::Result<pl::DataType, String> {
+        let mut iter = self.0.iter();
+        let (_, first) = iter
+            .next()
+            .ok_or_else(|| "cannot find supertype of an empty DataTypeVector".to_string())?;
+        iter.try_fold(first.clone(), |acc, (name, dt)| {
+            polars_core::utils::get_supertype(&acc, dt).ok_or_else(|| {
+                format!(
+                    "no supertype of {} and {} (column [{}])",
+                    acc,
+                    dt,
+                    name.as_deref().unwrap_or("<unnamed>")
+                )
+            })
+        })
+    }
+
     pub fn dtv_to_vec(&self) -> Vec<pl::DataType> {
         let v: Vec<_> = self.0.iter().map(|(_, dt)| dt.clone()).collect();
         v
     }
 }
 
+pub fn robj_to_datatype(robj: &Robj) -> std::result::Result<DataType, String> {
+    if !robj.inherits("DataType") || robj.rtype() != extendr_api::Rtype::ExternalPtr {
+        return Err("Internal error: Object is not a DataType".into());
+    }
+    //safety checks class and type before conversion
+    Ok(unsafe { &mut *robj.external_ptr_addr::<DataType>() }.clone())
+}
+
+//walk both DataTypes in parallel, path points to nested location e.g. "[inner].field_a"
+//supertype, except a Utf8 and a non-Utf8 (but Null) DataType have none, e.g. no implicit casting
+//of numbers to strings
+fn strict_supertype(left: &pl::DataType, right: &pl::DataType) -> Option<pl::DataType> {
+    use pl::DataType::{Null, Utf8};
+    let utf8_mix = matches!(left, Utf8) != matches!(right, Utf8)
+        && !matches!(left, Null)
+        && !matches!(right, Null);
+    polars_core::utils::get_supertype(left, right).filter(|_| !utf8_mix)
+}
+
+fn dtype_r_export_lossy(dt: &pl::DataType) -> bool {
+    use pl::DataType::*;
+    match dt {
+        //values beyond 2^53 are not exact in R doubles
+        Int64 | UInt64 => true,
+        List(inner) => dtype_r_export_lossy(inner),
+        Struct(fields) => fields.iter().any(|f| dtype_r_export_lossy(f.data_type())),
+        _ => false,
+    }
+}
+
+fn dtype_diff(left: &pl::DataType, right: &pl::DataType, path: &str) -> Option<String> {
+    use pl::DataType::*;
+    let at = if path.is_empty() {
+        String::new()
+    } else {
+        format!(" at {}", path)
+    };
+    match (left, right) {
+        (List(l_inner), List(r_inner)) => dtype_diff(l_inner, r_inner, &format!("{}[inner]", path)),
+        (Datetime(l_tu, l_tz), Datetime(r_tu, r_tz)) => {
+            if l_tu != r_tu {
+                Some(format!("time unit {} != {}{}", l_tu, r_tu, at))
+            } else if l_tz != r_tz {
+                Some(format!("time zone {:?} != {:?}{}", l_tz, r_tz, at))
+            } else {
+                None
+            }
+        }
+        (Duration(l_tu), Duration(r_tu)) if l_tu != r_tu => {
+            Some(format!("time unit {} != {}{}", l_tu, r_tu, at))
+        }
+        (Struct(l_fields), Struct(r_fields)) => {
+            if l_fields.len() != r_fields.len() {
+                return Some(format!(
+                    "struct has {} fields != {} fields{}",
+                    l_fields.len(),
+                    r_fields.len(),
+                    at
+                ));
+            }
+            l_fields.iter().zip(r_fields.iter()).find_map(|(lf, rf)| {
+                if lf.name() != rf.name() {
+                    Some(format!("field name {} != {}{}", lf.name(), rf.name(), at))
+                } else {
+                    dtype_diff(lf.data_type(), rf.data_type(), &format!("{}.{}", path, lf.name()))
+                }
+            })
+        }
+        (l, r) if l != r => Some(format!("{} != {}{}", l, r, at)),
+        _ => None,
+    }
+}
+
+pub fn new_time_unit(s: &str) -> std::result::Result<pl::TimeUnit, String> {
+    use pl::TimeUnit as TU;
+    match s {
+        "ns" => Ok(TU::Nanoseconds),
+        "us" => Ok(TU::Microseconds),
+        "ms" => Ok(TU::Milliseconds),
+        _ => Err(format!(
+            "TimeUnit choice: [{}] is not any of 'ns', 'us' or 'ms'",
+            s
+        )),
+    }
+}
+
+fn widening_rank(dt: &pl::DataType) -> Option<i32> {
+    use pl::DataType::*;
+    match dt {
+        Boolean => Some(0),
+        UInt8 => Some(1),
+        Int8 => Some(2),
+        UInt16 => Some(3),
+        Int16 => Some(4),
+        UInt32 => Some(5),
+        Int32 => Some(6),
+        UInt64 => Some(7),
+        Int64 => Some(8),
+        Float32 => Some(9),
+        Float64 => Some(10),
+        _ => None,
+    }
+}
+
+//check also any nested inner DataType for Unknown
+fn contains_unknown(dt: &pl::DataType) -> bool {
+    match dt {
+        pl::DataType::Unknown => true,
+        pl::DataType::List(inner) => contains_unknown(inner),
+        pl::DataType::Struct(fields) => fields.iter().any(|f| contains_unknown(f.data_type())),
+        _ => false,
+    }
+}
+
+//replace any Unknown in a declared DataType with the counterpart of the DataType inferred from
+//data, e.g. List(Unknown) and List(Int32) gives List(Int32). Where the two do not line up,
+//Unknown becomes Null.
+pub fn resolve_unknown(declared: &pl::DataType, inferred: &pl::DataType) -> pl::DataType {
+    use pl::DataType::*;
+    match (declared, inferred) {
+        (Unknown, Unknown) => Null,
+        (Unknown, inferred) => inferred.clone(),
+        (List(d), List(i)) => List(Box::new(resolve_unknown(d, i))),
+        (List(d), _) => List(Box::new(resolve_unknown(d, &Null))),
+        (Struct(d_fields), i) => {
+            let i_fields: &[pl::Field] = match i {
+                Struct(i_fields) => i_fields,
+                _ => &[],
+            };
+            Struct(
+                d_fields
+                    .iter()
+                    .map(|d| {
+                        let i = i_fields
+                            .iter()
+                            .find(|i| i.name() == d.name())
+                            .map_or(Null, |i| i.data_type().clone());
+                        pl::Field::new(d.name(), resolve_unknown(d.data_type(), &i))
+                    })
+                    .collect(),
+            )
+        }
+        (declared, _) => declared.clone(),
+    }
+}
+
 pub fn new_join_type(s: &str) -> pl::JoinType {
     match s {
         "cross" => pl::JoinType::Cross,
@@ -164,17 +847,52 @@ pub fn new_join_type(s: &str) -> pl::JoinType {
     }
 }
 
+//case-insensitive. Also accepts the other numpy/pandas quantile methods (Hyndman & Fan types 1-9)
+//mapped to the closest polars option, which approximates them:
+//discontinuous types 1-3 to 'higher', 'midpoint', 'nearest' and continuous types 4-9 to 'linear'.
 pub fn new_quantile_interpolation_option(
     s: &str,
 ) -> std::result::Result<QuantileInterpolOptions, String> {
     use pl::QuantileInterpolOptions::*;
-    match s {
+    match s.to_lowercase().as_str() {
         "nearest" => Ok(Nearest),
         "higher" => Ok(Higher),
         "lower" => Ok(Lower),
         "midpoint" => Ok(Midpoint),
         "linear" => Ok(Linear),
-        _ => Err(format!("interpolation choice: [{}] is not any of 'nearest', 'higher', 'lower', 'midpoint', 'linear'",s))
+        "inverted_cdf" => Ok(Higher),
+        "averaged_inverted_cdf" => Ok(Midpoint),
+        "closest_observation" => Ok(Nearest),
+        "interpolated_inverted_cdf" | "hazen" | "weibull" | "median_unbiased"
+        | "normal_unbiased" => Ok(Linear),
+        _ => Err(format!("interpolation choice: [{}] is not any of 'nearest', 'higher', 'lower', 'midpoint', 'linear' \
+            or numpy method 'inverted_cdf', 'averaged_inverted_cdf', 'closest_observation', \
+            'interpolated_inverted_cdf', 'hazen', 'weibull', 'median_unbiased', 'normal_unbiased'",s))
+    }
+}
+
+pub fn new_cast_strictness(s: &str) -> std::result::Result<bool, String> {
+    match s {
+        "strict" => Ok(true),
+        "non_strict" => Ok(false),
+        _ => Err(format!(
+            "cast strictness choice: [{}] is not any of 'strict' or 'non_strict'",
+            s
+        )),
+    }
+}
+
+pub fn new_parallel_strategy(s: &str) -> std::result::Result<pl::ParallelStrategy, String> {
+    use pl::ParallelStrategy as PS;
+    match s.to_lowercase().as_str() {
+        "auto" => Ok(PS::Auto),
+        "columns" => Ok(PS::Columns),
+        "row_groups" | "rowgroups" => Ok(PS::RowGroups),
+        "none" => Ok(PS::None),
+        _ => Err(format!(
+            "ParallelStrategy choice: [{}] is not any of 'auto', 'columns', 'row_groups' or 'none'",
+            s
+        )),
     }
 }
 
@@ -192,6 +910,87 @@ pub fn new_closed_window(s: &str) -> std::result::Result<pl::ClosedWindow, Strin
     }
 }
 
+//how an expression output is named, relative to its root column name
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NameStrategy {
+    Keep,
+    Prefix,
+    Suffix,
+}
+
+pub fn new_name_strategy(s: &str) -> std::result::Result<NameStrategy, String> {
+    match s {
+        "keep" => Ok(NameStrategy::Keep),
+        "prefix" => Ok(NameStrategy::Prefix),
+        "suffix" => Ok(NameStrategy::Suffix),
+        "map" => Err("NameStrategy choice: [map] needs a function, use $map_alias() instead".into()),
+        _ => Err(format!(
+            "NameStrategy choice: [{}] is not any of 'keep', 'prefix' or 'suffix'",
+            s
+        )),
+    }
+}
+
+//affix is ignored for Keep
+pub fn apply_name_strategy(expr: pl::Expr, strategy: NameStrategy, affix: &str) -> pl::Expr {
+    match strategy {
+        NameStrategy::Keep => expr.keep_name(),
+        NameStrategy::Prefix => expr.prefix(affix),
+        NameStrategy::Suffix => expr.suffix(affix),
+    }
+}
+
+pub enum BooleanAgg {
+    Any,
+    All,
+}
+
+pub fn new_boolean_agg(s: &str) -> std::result::Result<BooleanAgg, String> {
+    match s {
+        "any" => Ok(BooleanAgg::Any),
+        "all" => Ok(BooleanAgg::All),
+        _ => Err(format!(
+            "BooleanAgg choice: [{}] is not any of 'any' or 'all'",
+            s
+        )),
+    }
+}
+
+//strategy to concatenate DataFrames. Relaxed variants first cast columns of the same name to
+//their supertype.
+pub enum ConcatHow {
+    Vertical,
+    VerticalRelaxed,
+    Horizontal,
+    Diagonal,
+    DiagonalRelaxed,
+}
+
+pub fn new_concat_how(s: &str) -> std::result::Result<ConcatHow, String> {
+    match s {
+        "vertical" => Ok(ConcatHow::Vertical),
+        "vertical_relaxed" => Ok(ConcatHow::VerticalRelaxed),
+        "horizontal" => Ok(ConcatHow::Horizontal),
+        "diagonal" => Ok(ConcatHow::Diagonal),
+        "diagonal_relaxed" => Ok(ConcatHow::DiagonalRelaxed),
+        _ => Err(format!(
+            "ConcatHow choice: [{}] is not any of 'vertical', 'vertical_relaxed', 'horizontal', \
+            'diagonal' or 'diagonal_relaxed'",
+            s
+        )),
+    }
+}
+
+//endpoints of a range, polars reuses ClosedWindow for this
+pub fn new_closed_interval(s: &str) -> std::result::Result<pl::ClosedWindow, String> {
+    new_closed_window(s).map_err(|_| {
+        format!(
+            "ClosedInterval choice: [{}] is not any of 'both', 'left', 'none' or 'right'",
+            s
+        )
+    })
+}
+
 pub fn new_null_behavior(
     s: &str,
 ) -> std::result::Result<polars::series::ops::NullBehavior, String> {
@@ -207,18 +1006,21 @@ pub fn new_null_behavior(
     }
 }
 
-pub fn new_rank_method(s: &str) -> std::result::Result<pl::RankMethod, String> {
+//returns the RankMethod and whether ties are ranked in reverse encounter order. polars has no
+//such method, 'last' is ordinal ranking of the reversed values, reversed back.
+pub fn new_rank_method(s: &str) -> std::result::Result<(pl::RankMethod, bool), String> {
     use pl::RankMethod as RM;
     let s_low = s.to_lowercase();
     match s_low.as_str() {
-        "average" => Ok(RM::Average),
-        "dense" => Ok(RM::Dense),
-        "max" => Ok(RM::Max),
-        "min" => Ok(RM::Min),
-        "ordinal" => Ok(RM::Ordinal),
-        "random" => Ok(RM::Random),
+        "average" => Ok((RM::Average, false)),
+        "dense" => Ok((RM::Dense, false)),
+        "max" => Ok((RM::Max, false)),
+        "min" => Ok((RM::Min, false)),
+        "ordinal" | "first" => Ok((RM::Ordinal, false)),
+        "last" => Ok((RM::Ordinal, true)),
+        "random" => Ok((RM::Random, false)),
         _ => Err(format!(
-            "RankMethod choice: [{}] is not any 'average','dense', 'min', 'max', 'ordinal', 'random'",
+            "RankMethod choice: [{}] is not any 'average','dense', 'min', 'max', 'ordinal', 'first', 'last', 'random'",
             s_low.as_str()
         )),
     }
@@ -252,12 +1054,8 @@ pub fn literal_to_any_value(
         lv::UInt64(x) => Ok(av::UInt64(x)),
         lv::UInt8(x) => Ok(av::UInt8(x)),
         // lv::Utf8(x) => Ok(av::Utf8(x.as_str())),
-        lv::Utf8(x) => {
-            let mut s = SString::new();
-
-            s.push_str(x.as_str());
-            Ok(av::Utf8Owned(s))
-        }
+        //strings longer than the inline capacity become boxed, the owned String is reused
+        lv::Utf8(x) => Ok(av::Utf8Owned(SString::from(x))),
         x => Err(format!("cannot convert LiteralValue {:?} to AnyValue", x)),
     }
 }
@@ -282,6 +1080,10 @@ pub fn literal_to_any_value(
 //     x
 // }
 
+// Every choice accepted by new_interpolation_method, keep in sync with its match arms.
+pub const INTERPOLATION_METHODS: [&str; 2] = ["linear", "nearest"];
+
+// Shared parser for any interpolation path, e.g. interpolate(). Do not duplicate the match.
 pub fn new_interpolation_method(s: &str) -> std::result::Result<pl::InterpolationMethod, String> {
     use pl::InterpolationMethod as IM;
     match s {
@@ -289,14 +1091,88 @@ pub fn new_interpolation_method(s: &str) -> std::result::Result<pl::Interpolatio
         "nearest" => Ok(IM::Nearest),
 
         _ => Err(format!(
-            "InterpolationMethod choice: [{}] is not any of 'linear' or 'nearest'",
-            s
+            "InterpolationMethod choice: [{}] is not any of {}",
+            s,
+            INTERPOLATION_METHODS
+                .iter()
+                .map(|m| format!("'{}'", m))
+                .collect::<Vec<_>>()
+                .join(", ")
         )),
     }
 }
 
+// Build EWMOptions from exactly one decay specifier of com, span, half_life or alpha, which are
+// all mapped to the smoothing factor alpha. EWMOptions of this polars version has no ignore_nulls.
+pub fn new_ewm_options(
+    com: Option<f64>,
+    span: Option<f64>,
+    half_life: Option<f64>,
+    alpha: Option<f64>,
+    adjust: bool,
+    bias: bool,
+    min_periods: f64,
+) -> std::result::Result<pl::EWMOptions, String> {
+    let alpha = match (com, span, half_life, alpha) {
+        (Some(com), None, None, None) => {
+            if !(com >= 0.0) {
+                return Err(format!("EWM com: [{}] must be non-negative", com));
+            }
+            1.0 / (1.0 + com)
+        }
+        (None, Some(span), None, None) => {
+            if !(span >= 1.0) {
+                return Err(format!("EWM span: [{}] must be at least 1", span));
+            }
+            2.0 / (span + 1.0)
+        }
+        (None, None, Some(half_life), None) => {
+            if !(half_life > 0.0) {
+                return Err(format!("EWM half_life: [{}] must be positive", half_life));
+            }
+            1.0 - (-std::f64::consts::LN_2 / half_life).exp()
+        }
+        (None, None, None, Some(alpha)) => {
+            if !(alpha > 0.0 && alpha <= 1.0) {
+                return Err(format!("EWM alpha: [{}] must be in ]0; 1]", alpha));
+            }
+            alpha
+        }
+        (None, None, None, None) => {
+            return Err(
+                "EWM decay: one of 'com', 'span', 'half_life' or 'alpha' must be set".into(),
+            )
+        }
+        _ => {
+            let set: Vec<String> = [
+                ("com", com),
+                ("span", span),
+                ("half_life", half_life),
+                ("alpha", alpha),
+            ]
+            .iter()
+            .filter(|(_, x)| x.is_some())
+            .map(|(name, _)| format!("'{}'", name))
+            .collect();
+            return Err(format!(
+                "EWM decay: only one of 'com', 'span', 'half_life' or 'alpha' can be set, not {}",
+                set.join(", ")
+            ));
+        }
+    };
+    let min_periods = crate::utils::try_f64_into_usize(min_periods, false)
+        .map_err(|err| format!("EWM min_periods: {}", err))?;
+    Ok(pl::EWMOptions {
+        alpha,
+        adjust,
+        bias,
+        min_periods,
+    })
+}
+
 extendr_module! {
     mod rdatatype;
     impl DataType;
+    impl Field;
     impl DataTypeVector;
 }
@@ -2,6 +2,7 @@ use crate::rdataframe::rexpr::Expr;
 use crate::rdataframe::DataFrame;
 use crate::{rdataframe::VecDataFrame, utils::r_result_list};
 
+use crate::rdataframe::r_to_series;
 use crate::rdataframe::rexpr::ProtoExprArray;
 use extendr_api::prelude::*;
 use polars::prelude as pl;
@@ -41,6 +42,36 @@ fn concat_df(vdf: &VecDataFrame) -> List {
 }
 //ping
 
+//integer range literal from start to end by step, endpoints included as of closed
+#[extendr]
+fn int_range(start: f64, end: f64, step: f64, closed: &str) -> List {
+    use crate::rdatatype::new_closed_interval;
+    use crate::utils::try_f64_into_i64;
+    let res = || -> std::result::Result<Expr, String> {
+        let (start, end, step) = (
+            try_f64_into_i64(start)?,
+            try_f64_into_i64(end)?,
+            try_f64_into_i64(step)?,
+        );
+        if step == 0 {
+            return Err("int_range: step must not be zero".into());
+        }
+        let (incl_start, incl_end) = match new_closed_interval(closed)? {
+            pl::ClosedWindow::Both => (true, true),
+            pl::ClosedWindow::Left => (true, false),
+            pl::ClosedWindow::Right => (false, true),
+            pl::ClosedWindow::None => (false, false),
+        };
+        let before_end = |v: i64| if step > 0 { v < end } else { v > end };
+        let values: Vec<i64> = std::iter::successors(Some(start), |v| v.checked_add(step))
+            .take_while(|v| before_end(*v) || (incl_end && *v == end))
+            .filter(|v| incl_start || *v != start)
+            .collect();
+        Ok(Expr(pl::lit(pl::Series::new("int_range", values))))
+    }();
+    r_result_list(res)
+}
+
 #[extendr]
 fn diag_concat_df(dfs: &VecDataFrame) -> List {
     let df = pl_functions::diag_concat_df(&dfs.0[..]).map(|ok| DataFrame(ok));
@@ -53,6 +84,67 @@ pub fn hor_concat_df(dfs: &VecDataFrame) -> List {
     r_result_list(df)
 }
 
+//cast any columns of the same name to their common supertype in all DataFrames
+fn relax_concat_dtypes(dfs: &[pl::DataFrame]) -> pl::PolarsResult<Vec<pl::DataFrame>> {
+    let mut supertypes: Vec<(String, pl::DataType)> = Vec::new();
+    for s in dfs.iter().flat_map(|df| df.get_columns().iter()) {
+        match supertypes.iter_mut().find(|(name, _)| name == s.name()) {
+            Some((name, dt)) => {
+                *dt = polars_core::utils::get_supertype(dt, s.dtype()).ok_or_else(|| {
+                    pl::PolarsError::SchemaMisMatch(polars::error::ErrString::Owned(format!(
+                        "relaxed concat: column [{}] has no supertype of {} and {}",
+                        name,
+                        dt,
+                        s.dtype()
+                    )))
+                })?;
+            }
+            None => supertypes.push((s.name().to_string(), s.dtype().clone())),
+        }
+    }
+    dfs.iter()
+        .map(|df| {
+            let columns = df
+                .get_columns()
+                .iter()
+                .map(|s| {
+                    let (_, dt) = supertypes
+                        .iter()
+                        .find(|(name, _)| name == s.name())
+                        .expect("all names were visited");
+                    s.cast(dt)
+                })
+                .collect::<pl::PolarsResult<Vec<_>>>()?;
+            pl::DataFrame::new(columns)
+        })
+        .collect()
+}
+
+//concatenate DataFrames as of how, see new_concat_how
+#[extendr]
+fn concat_df_how(vdf: &VecDataFrame, how: &str) -> List {
+    use crate::rdatatype::{new_concat_how, ConcatHow};
+    let relaxed = || {
+        relax_concat_dtypes(&vdf.0[..])
+            .map(VecDataFrame)
+            .map_err(|err| err.to_string())
+    };
+    match new_concat_how(how) {
+        Ok(ConcatHow::Vertical) => concat_df(vdf),
+        Ok(ConcatHow::VerticalRelaxed) => match relaxed() {
+            Ok(vdf) => concat_df(&vdf),
+            Err(err) => r_result_list(Err::<DataFrame, String>(err)),
+        },
+        Ok(ConcatHow::Horizontal) => hor_concat_df(vdf),
+        Ok(ConcatHow::Diagonal) => diag_concat_df(vdf),
+        Ok(ConcatHow::DiagonalRelaxed) => match relaxed() {
+            Ok(vdf) => diag_concat_df(&vdf),
+            Err(err) => r_result_list(Err::<DataFrame, String>(err)),
+        },
+        Err(err) => r_result_list(Err::<DataFrame, String>(err)),
+    }
+}
+
 #[extendr]
 pub fn mem_address(robj: Robj) -> String {
     let ptr_raw = unsafe { robj.external_ptr_addr::<usize>() };
@@ -84,14 +176,112 @@ fn sum_exprs(exprs: &ProtoExprArray) -> Expr {
     polars::lazy::dsl::sum_exprs(exprs).into()
 }
 
+#[extendr]
+fn set_conversion_stats(enable: bool) {
+    r_to_series::set_conversion_stats(enable);
+}
+
+#[extendr]
+fn reset_conversion_stats() {
+    r_to_series::reset_conversion_stats();
+}
+
+#[extendr]
+fn get_conversion_stats() -> List {
+    let stats = r_to_series::get_conversion_stats();
+    list!(
+        branch = r_to_series::CONVERSION_STATS_BRANCHES.to_vec(),
+        calls = stats.iter().map(|(c, _)| *c as f64).collect::<Vec<f64>>(),
+        elements = stats.iter().map(|(_, e)| *e as f64).collect::<Vec<f64>>()
+    )
+}
+
+#[extendr]
+fn set_doubles_as_float32(enable: bool) {
+    r_to_series::set_doubles_as_float32(enable);
+}
+
+#[extendr]
+fn get_doubles_as_float32() -> bool {
+    r_to_series::get_doubles_as_float32()
+}
+
+#[extendr]
+fn supported_r_classes() -> Vec<String> {
+    r_to_series::SUPPORTED_R_CLASSES
+        .iter()
+        .map(|cl| cl.to_string())
+        .collect()
+}
+
+//time conversion of representative R columns, see rdataframe/conversion_bench.rs
+#[extendr]
+fn conversion_bench(n: i32, reps: i32) -> List {
+    #[cfg(feature = "conversion_bench")]
+    let res = crate::rdataframe::conversion_bench::run(n, reps);
+    #[cfg(not(feature = "conversion_bench"))]
+    let res: std::result::Result<List, String> = Err(format!(
+        "rpolars was built without cargo feature conversion_bench, cannot time n [{}] x reps [{}]",
+        n, reps
+    ));
+    r_result_list(res)
+}
+
+#[extendr]
+fn interpolation_methods() -> Vec<String> {
+    crate::rdatatype::INTERPOLATION_METHODS
+        .iter()
+        .map(|m| m.to_string())
+        .collect()
+}
+
+//validate dynamic groupby options from R, ok NULL if valid. The options bundle stays internal to
+//rust until groupby_dynamic is exposed.
+#[extendr]
+#[allow(clippy::too_many_arguments)]
+fn dynamic_group_options(
+    index_column: &str,
+    every: &str,
+    period: Nullable<String>,
+    offset: Nullable<String>,
+    closed: &str,
+    label: &str,
+    include_boundaries: bool,
+    start_by: &str,
+) -> List {
+    let res = crate::rdataframe::rexpr::make_dynamic_group_options(
+        index_column,
+        every,
+        period,
+        offset,
+        closed,
+        label,
+        include_boundaries,
+        start_by,
+    )
+    .map(|_| ());
+    r_result_list(res)
+}
+
 extendr_module! {
     mod rlib;
     fn concat_df;
     fn hor_concat_df;
     fn diag_concat_df;
+    fn concat_df_how;
     fn min_exprs;
     fn max_exprs;
     fn coalesce_exprs;
     fn sum_exprs;
     fn mem_address;
+    fn set_conversion_stats;
+    fn reset_conversion_stats;
+    fn get_conversion_stats;
+    fn set_doubles_as_float32;
+    fn get_doubles_as_float32;
+    fn supported_r_classes;
+    fn int_range;
+    fn conversion_bench;
+    fn interpolation_methods;
+    fn dynamic_group_options;
 }